@@ -0,0 +1,227 @@
+use std::error::Error;
+use std::io::{self, IsTerminal, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc_utils::{
+    check_session_cookie, prompt_session_cookie, DownloadError, Profile, ProfileError,
+    ProfileStore, ReqwestClient, DEFAULT_PROFILE,
+};
+use clap::{Args, Subcommand};
+
+use crate::configured_year;
+
+#[derive(Subcommand, Debug)]
+pub enum AuthCommand {
+    /// Check a session cookie and save it for a profile
+    Login {
+        /// The name of the profile, e.g. to use several accounts
+        #[arg(long, default_value = DEFAULT_PROFILE)]
+        profile: String,
+
+        /// The directory for the inputs of the profile [default: `inputs` for the default profile, `inputs/<profile>` otherwise]
+        #[arg(long = "inputs", value_name = "DIR")]
+        inputs_dir: Option<PathBuf>,
+
+        #[command(flatten)]
+        check: CheckArgs,
+    },
+
+    /// Check the session cookies of all profiles
+    Status {
+        #[command(flatten)]
+        check: CheckArgs,
+    },
+
+    /// Use another profile by default
+    Use {
+        /// The name of the profile
+        profile: String,
+    },
+
+    /// Remove a profile and its session cookie
+    Logout {
+        /// The name of the profile
+        profile: String,
+    },
+}
+
+/// The options for checking a session cookie against the server.
+#[derive(Args, Debug)]
+pub struct CheckArgs {
+    /// A page that requires logging in, used to check the session cookie [default: the input of the first day of the year]
+    #[arg(long, env = "AOC_AUTH_ENDPOINT")]
+    endpoint: Option<String>,
+
+    /// The year whose first input is used to check the session cookie
+    #[arg(long, default_value_t = configured_year())]
+    year: u16,
+
+    /// The URL of the Advent of Code server
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    base_url: String,
+}
+
+impl CheckArgs {
+    /// The page used to check the session cookie.
+    fn endpoint(&self) -> String {
+        self.endpoint
+            .clone()
+            .unwrap_or_else(|| format!("{}/{}/day/1/input", self.base_url, self.year))
+    }
+}
+
+/// Manage the session cookies of the profiles.
+pub fn auth(command: AuthCommand) -> ExitCode {
+    let result = match command {
+        AuthCommand::Login {
+            profile,
+            inputs_dir,
+            check,
+        } => login(&profile, inputs_dir, &check),
+        AuthCommand::Status { check } => status(&check),
+        AuthCommand::Use { profile } => use_profile(&profile),
+        AuthCommand::Logout { profile } => logout(&profile),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn login(
+    name: &str,
+    inputs_dir: Option<PathBuf>,
+    check: &CheckArgs,
+) -> Result<bool, Box<dyn Error>> {
+    let path = ProfileStore::default_path()?;
+    let mut store = ProfileStore::load(&path)?;
+
+    // A piped cookie doesn't end up in the shell history or the process list, unlike an argument
+    let session_cookie = if io::stdin().is_terminal() {
+        prompt_session_cookie()?
+    } else {
+        let mut cookie = String::new();
+        io::stdin().read_to_string(&mut cookie)?;
+        cookie.trim().to_string()
+    };
+
+    if session_cookie.is_empty() {
+        return Err("No session cookie provided".into());
+    }
+
+    match check_session_cookie(&ReqwestClient::new(), &check.endpoint(), &session_cookie) {
+        Ok(()) => {}
+        Err(DownloadError::SessionExpired) => {
+            eprintln!("The server rejected the session cookie, it is invalid or has expired.");
+            return Ok(false);
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    let mut profile = Profile::new(name, &session_cookie);
+    if let Some(inputs_dir) = inputs_dir {
+        profile.inputs_dir = inputs_dir;
+    } else if let Some(existing) = store.profiles.get(name) {
+        profile.inputs_dir = existing.inputs_dir.clone();
+    }
+
+    eprintln!(
+        "The session cookie is valid, saved the profile {name:?} with the inputs in {}",
+        profile.inputs_dir.display()
+    );
+
+    store.insert(name, profile);
+    store.save(&path)?;
+
+    Ok(true)
+}
+
+fn status(check: &CheckArgs) -> Result<bool, Box<dyn Error>> {
+    let path = ProfileStore::default_path()?;
+    let mut store = ProfileStore::load(&path)?;
+
+    if store.profiles.is_empty() {
+        eprintln!(
+            "No profiles saved in {}, add one with `aoc auth login`.",
+            path.display()
+        );
+        return Ok(false);
+    }
+
+    let client = ReqwestClient::new();
+    let endpoint = check.endpoint();
+    let mut all_valid = true;
+
+    for (name, profile) in &mut store.profiles {
+        let status = match check_session_cookie(&client, &endpoint, &profile.session_cookie) {
+            Ok(()) => {
+                profile.expired = false;
+                "valid".to_string()
+            }
+            Err(DownloadError::SessionExpired) => {
+                profile.expired = true;
+                all_valid = false;
+                "EXPIRED".to_string()
+            }
+            Err(err) => {
+                all_valid = false;
+                format!("unknown: {err}")
+            }
+        };
+
+        let marker = if store.default.as_ref() == Some(name) {
+            "*"
+        } else {
+            " "
+        };
+        eprintln!(
+            "{marker} {name}  inputs in {}  {status}",
+            profile.inputs_dir.display()
+        );
+    }
+
+    store.save(&path)?;
+
+    Ok(all_valid)
+}
+
+fn use_profile(name: &str) -> Result<bool, Box<dyn Error>> {
+    let path = ProfileStore::default_path()?;
+    let mut store = ProfileStore::load(&path)?;
+
+    if !store.profiles.contains_key(name) {
+        return Err(ProfileError::Unknown {
+            name: name.to_string(),
+        }
+        .into());
+    }
+
+    store.default = Some(name.to_string());
+    store.save(&path)?;
+    eprintln!("Using the profile {name:?} by default");
+
+    Ok(true)
+}
+
+fn logout(name: &str) -> Result<bool, Box<dyn Error>> {
+    let path = ProfileStore::default_path()?;
+    let mut store = ProfileStore::load(&path)?;
+
+    if store.remove(name).is_none() {
+        return Err(ProfileError::Unknown {
+            name: name.to_string(),
+        }
+        .into());
+    }
+
+    store.save(&path)?;
+    eprintln!("Removed the profile {name:?}");
+
+    Ok(true)
+}
@@ -0,0 +1,316 @@
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_utils::{
+    default_jobs, extract_examples, parse_duration, print_inputs_reports, puzzle_page_path,
+    run_inputs_dir, run_parallel, summary_table, total_time, DayReport, OutputFormat, RunArgs,
+    SortOrder,
+};
+use auth::{auth, AuthCommand};
+use clap::{Args, Parser, Subcommand};
+use progress::{collect_progress, progress_table, update_readme};
+use read::{read, ReadArgs};
+use registry::all_days;
+use scaffold::DayTemplate;
+use selection::DaySelection;
+use wait::{wait, WaitArgs};
+
+mod auth;
+mod progress;
+mod read;
+mod registry;
+mod scaffold;
+mod selection;
+mod wait;
+
+#[derive(Parser, Debug)]
+#[command()]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the solutions of one or more days
+    Run {
+        /// The day to run, or a range of days, e.g. `5`, `3..9` or `3..=9`
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        days: Option<DaySelection>,
+
+        /// Run all days that have been implemented
+        #[arg(long)]
+        all: bool,
+
+        #[command(flatten)]
+        summary: SummaryArgs,
+
+        #[command(flatten)]
+        args: RunArgs,
+    },
+
+    /// Work out the status of each day and update the progress table in the README
+    Progress {
+        /// The README containing the progress table
+        #[arg(long, default_value = "README.md")]
+        readme: PathBuf,
+
+        /// Print the table instead of updating the README
+        #[arg(long)]
+        print: bool,
+
+        /// How long each part may run on the saved input, e.g. `30s`
+        #[arg(long, default_value = "1m", value_parser = parse_duration)]
+        timeout: Duration,
+    },
+
+    /// Create the crate for a new day and add it to the runner
+    New {
+        /// The day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Add `parser.rs` and `types.rs` modules for parsing the input with nom
+        #[arg(long)]
+        parser: bool,
+
+        /// Fill the test stub with the first example of the cached puzzle description
+        #[arg(long)]
+        example: bool,
+    },
+
+    /// Count down to the unlock of a puzzle, then download its input
+    Wait(WaitArgs),
+
+    /// Show the description of a puzzle in the terminal, or export the cached descriptions
+    Read(ReadArgs),
+
+    /// Manage the session cookies of one or more accounts
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommand,
+    },
+}
+
+/// The options for running several days and summarizing them.
+#[derive(Args, Debug)]
+struct SummaryArgs {
+    /// Run the days in parallel on a thread pool; the timings are less precise then
+    #[arg(long, conflicts_with_all = ["accept", "submit", "mem", "save_baseline"])]
+    parallel: bool,
+
+    /// The number of threads for `--parallel` [default: one per CPU]
+    #[arg(short, long, value_name = "N", requires = "parallel")]
+    jobs: Option<NonZeroUsize>,
+
+    /// How to order the summary of the days
+    #[arg(long, value_enum, default_value_t)]
+    sort: SortOrder,
+
+    /// How long all days together may take, e.g. `1s`; the exit status is non-zero if they take longer
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    budget: Option<Duration>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            days,
+            all: _,
+            summary,
+            args,
+        } => run(days, &summary, &args),
+        Command::Progress {
+            readme,
+            print,
+            timeout,
+        } => progress(&readme, print, timeout),
+        Command::New {
+            day,
+            parser,
+            example,
+        } => new_day(day, parser, example),
+        Command::Wait(args) => wait(&args),
+        Command::Read(args) => read(&args),
+        Command::Auth { command } => auth(command),
+    }
+}
+
+/// Create the crate for a new day from the template.
+fn new_day(day: u8, parser: bool, example: bool) -> ExitCode {
+    let example = example.then(|| cached_example(day)).flatten();
+    let template = DayTemplate {
+        day,
+        parser,
+        example,
+    };
+    let workspace = Path::new(".");
+
+    let dir = match template.create(workspace) {
+        Ok(dir) => dir,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    eprintln!("Created {}", dir.display());
+
+    match template.register(workspace) {
+        Ok(changed) => {
+            for path in changed {
+                eprintln!("Added day {day} to {}", path.display());
+            }
+            eprintln!("Run it with `cargo run --bin day_{day:0>2}`");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The first example in the cached description of the puzzle, if it has been downloaded.
+fn cached_example(day: u8) -> Option<String> {
+    let page_path = puzzle_page_path(configured_year(), day);
+
+    let Ok(page) = fs::read_to_string(&page_path) else {
+        eprintln!(
+            "The puzzle description is not cached in {page_path} yet, run the day with `--example` to download it"
+        );
+        return None;
+    };
+
+    extract_examples(&page)
+        .into_iter()
+        .next()
+        .map(|example| example.trim_end().to_string())
+}
+
+/// The year of the registered days.
+fn configured_year() -> u16 {
+    all_days().first().map_or(2023, |day| day.year())
+}
+
+/// Work out the progress of all days and write it to the README.
+fn progress(readme: &Path, print: bool, timeout: Duration) -> ExitCode {
+    let days = all_days();
+    let year = configured_year();
+
+    let progress = collect_progress(Path::new("."), &days, year, timeout);
+    let table = progress_table(&progress);
+
+    if print {
+        print!("{table}");
+        return ExitCode::SUCCESS;
+    }
+
+    match update_readme(readme, &table) {
+        Ok(()) => {
+            let stars: usize = progress.iter().map(|day| day.stars()).sum();
+            eprintln!("Updated {} with {stars} stars", readme.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run the selected days, continuing with the next day if one fails.
+fn run(selection: Option<DaySelection>, summary: &SummaryArgs, args: &RunArgs) -> ExitCode {
+    let start = Instant::now();
+
+    let days: Vec<_> = all_days()
+        .into_iter()
+        .filter(|day| {
+            selection
+                .as_ref()
+                .is_none_or(|selection| selection.contains(day.day()))
+        })
+        .collect();
+
+    if days.is_empty() {
+        eprintln!("No solutions found for the selected days.");
+        return ExitCode::FAILURE;
+    }
+
+    if days.len() > 1 && args.input.is_some() {
+        eprintln!("The --input option can only be used when running a single day.");
+        return ExitCode::FAILURE;
+    }
+
+    if let Some(dir) = &args.inputs_dir {
+        let [day] = days.as_slice() else {
+            eprintln!("The --inputs-dir option can only be used when running a single day.");
+            return ExitCode::FAILURE;
+        };
+
+        return match run_inputs_dir(dir, args, |args| day.run(args)) {
+            Ok(reports) => {
+                print_inputs_reports(&reports, args.format);
+
+                if reports.iter().any(DayReport::needs_attention) {
+                    ExitCode::FAILURE
+                } else {
+                    ExitCode::SUCCESS
+                }
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let print_report = |report: &DayReport| {
+        report.print(args.format);
+
+        if args.format == OutputFormat::Text {
+            eprintln!();
+        }
+    };
+
+    let reports = if summary.parallel {
+        let jobs = summary.jobs.map_or_else(default_jobs, NonZeroUsize::get);
+        run_parallel(&days, args, jobs, print_report)
+    } else {
+        days.iter()
+            .map(|day| {
+                let report = day.run(args);
+                print_report(&report);
+                report
+            })
+            .collect()
+    };
+
+    let failed = reports.iter().filter(|report| report.failed()).count();
+
+    if args.format == OutputFormat::Text {
+        if reports.len() > 1 {
+            eprint!("{}", summary_table(&reports, summary.sort, summary.budget));
+        }
+
+        eprintln!("====\nRan {} days in {:?}", days.len(), start.elapsed());
+
+        if failed > 0 {
+            eprintln!("{failed} days failed");
+        }
+    }
+
+    let over_budget = summary
+        .budget
+        .is_some_and(|budget| total_time(&reports) > budget);
+
+    if failed > 0 || over_budget {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,559 @@
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use aoc_utils::{AnswerStatus, DayReport, DynAocDay, PartFailure, RunArgs};
+
+/// The number of days in the advent.
+const DAYS: u8 = 25;
+
+/// The markers around the progress table in the README.
+const START_MARKER: &str = "<!-- progress start -->";
+const END_MARKER: &str = "<!-- progress end -->";
+
+/// The progress of a day, worked out from the workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayProgress {
+    pub year: u16,
+    pub day: u8,
+    /// Whether the `days/day_XX` crate exists.
+    pub exists: bool,
+    pub parts: [PartProgress; 2],
+}
+
+/// The progress of a part of the puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartProgress {
+    /// Whether the part is implemented, or `None` if neither the input nor the examples tell.
+    pub implemented: Option<bool>,
+    pub examples: ExamplesStatus,
+    /// The status of the answer for the saved input, or `None` if there is no input.
+    pub answer: Option<AnswerStatus>,
+}
+
+/// The outcome of the example tests of a part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExamplesStatus {
+    /// The part has no example tests.
+    #[default]
+    Missing,
+    /// All example tests of the part passed.
+    Passed(usize),
+    /// Some example tests of the part failed, or they could not be built.
+    Failed,
+}
+
+impl DayProgress {
+    /// A star is earned for each implemented part that passes its example tests.
+    ///
+    /// The stars don't depend on the inputs and answers, which aren't committed, so the table
+    /// is the same for every checkout. A wrong answer for the saved input still costs the star.
+    pub fn stars(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|part| {
+                part.implemented != Some(false)
+                    && matches!(part.examples, ExamplesStatus::Passed(_))
+                    && !matches!(part.answer, Some(AnswerStatus::Wrong { .. }))
+            })
+            .count()
+    }
+}
+
+impl Display for PartProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.implemented == Some(false) {
+            return write!(f, "not implemented");
+        }
+
+        let answer = match &self.answer {
+            Some(AnswerStatus::Verified) => "verified",
+            Some(AnswerStatus::Wrong { .. }) => "wrong answer",
+            Some(AnswerStatus::Unknown) => "unverified",
+            None => "no input",
+        };
+
+        match self.examples {
+            ExamplesStatus::Missing => write!(f, "{answer}, no examples"),
+            ExamplesStatus::Passed(1) => write!(f, "{answer}, 1 example passes"),
+            ExamplesStatus::Passed(count) => write!(f, "{answer}, {count} examples pass"),
+            ExamplesStatus::Failed => write!(f, "{answer}, examples fail"),
+        }
+    }
+}
+
+/// The reasons why the progress table could not be written.
+#[derive(Debug)]
+pub enum ProgressError {
+    Io { path: PathBuf, err: io::Error },
+    MissingMarkers { path: PathBuf },
+}
+
+impl Display for ProgressError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, err } => write!(f, "Failed to access {}: {err}", path.display()),
+            Self::MissingMarkers { path } => write!(
+                f,
+                "{} has no progress table between {START_MARKER} and {END_MARKER}",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProgressError {}
+
+/// Work out the progress of every day of the advent.
+///
+/// The registered days are run on their saved input, if there is one, without downloading anything.
+/// The example tests are run with `cargo test` for each crate that exists.
+pub fn collect_progress(
+    workspace: &Path,
+    days: &[Box<dyn DynAocDay>],
+    year: u16,
+    timeout: Duration,
+) -> Vec<DayProgress> {
+    (1..=DAYS)
+        .map(|day| {
+            let crate_name = format!("day_{day:0>2}");
+            let exists = workspace
+                .join("days")
+                .join(&crate_name)
+                .join("Cargo.toml")
+                .is_file();
+
+            let mut progress = DayProgress {
+                year,
+                day,
+                exists,
+                parts: Default::default(),
+            };
+
+            if !exists {
+                return progress;
+            }
+
+            eprintln!("Checking {crate_name}...");
+
+            let examples = run_example_tests(workspace, &crate_name);
+            for (part, (examples, not_implemented)) in progress.parts.iter_mut().zip(examples) {
+                part.examples = examples;
+                if not_implemented {
+                    part.implemented = Some(false);
+                } else if matches!(examples, ExamplesStatus::Passed(_)) {
+                    part.implemented = Some(true);
+                }
+            }
+
+            let registered = days.iter().find(|registered| registered.day() == day);
+            if let Some(registered) = registered {
+                // The examples of an unsolved part only check that it returns `()`
+                for (part, unsolved) in progress.parts.iter_mut().zip(registered.unsolved_parts()) {
+                    if unsolved {
+                        part.implemented = Some(false);
+                    }
+                }
+
+                let input = workspace.join(registered.input_path());
+                if input.is_file() {
+                    let report = registered.run(&RunArgs {
+                        input: Some(input),
+                        no_prompt: true,
+                        timeout: Some(timeout),
+                        ..Default::default()
+                    });
+                    apply_report(&mut progress.parts, &report);
+                }
+
+                if progress.parts.iter().any(|part| part.implemented.is_none()) {
+                    probe_parts(&mut progress.parts, registered.as_ref(), timeout);
+                }
+            }
+
+            progress
+        })
+        .collect()
+}
+
+/// Update the progress of the parts with the result of running the day on its input.
+fn apply_report(parts: &mut [PartProgress; 2], report: &DayReport) {
+    let results = [
+        (&report.part_1, &report.part_1_failure),
+        (&report.part_2, &report.part_2_failure),
+    ];
+
+    for (part, (result, failure)) in parts.iter_mut().zip(results) {
+        match (result, failure) {
+            (Some(result), _) if result.answer.is_some() => {
+                part.implemented = Some(true);
+                part.answer = Some(result.status.clone());
+            }
+            // The part returns `()` because it hasn't been solved yet
+            (Some(_), _) | (None, Some(PartFailure::NotImplemented)) => {
+                part.implemented = Some(false);
+            }
+            // The part exists, but panicked or timed out on the input
+            (None, Some(_)) => {
+                part.implemented = Some(true);
+                part.answer = Some(AnswerStatus::Unknown);
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// Find the parts that are not implemented by running the day on an empty input.
+///
+/// Only a `todo!()` or `unimplemented!()` tells anything here; the other parts are expected to fail on the empty input.
+fn probe_parts(parts: &mut [PartProgress; 2], day: &dyn DynAocDay, timeout: Duration) {
+    let input = env::temp_dir().join("aoc_empty_input.txt");
+    if fs::write(&input, "").is_err() {
+        return;
+    }
+
+    let report = day.run(&RunArgs {
+        input: Some(input),
+        no_prompt: true,
+        timeout: Some(timeout),
+        ..Default::default()
+    });
+
+    let failures = [&report.part_1_failure, &report.part_2_failure];
+    for (part, failure) in parts.iter_mut().zip(failures) {
+        if part.implemented.is_none() && matches!(failure, Some(PartFailure::NotImplemented)) {
+            part.implemented = Some(false);
+        }
+    }
+}
+
+/// Run the example tests of a crate, returning the status of each part and whether it is unimplemented.
+fn run_example_tests(workspace: &Path, crate_name: &str) -> [(ExamplesStatus, bool); 2] {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .args(["test", "--package", crate_name, "--lib"])
+        .current_dir(workspace)
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() || !output.stdout.is_empty() => {
+            parse_test_output(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(output) => {
+            eprintln!(
+                "Failed to build the tests of {crate_name}:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            [(ExamplesStatus::Failed, false); 2]
+        }
+        Err(err) => {
+            eprintln!("Failed to run the tests of {crate_name}: {err}");
+            [(ExamplesStatus::Failed, false); 2]
+        }
+    }
+}
+
+/// Sort the results of the example tests by part, using the output of the test harness.
+///
+/// Example tests are the ones named after a part, e.g. `tests::example::part_1`.
+/// A failing test whose panic comes from `todo!()` or `unimplemented!()` marks the part as unimplemented.
+fn parse_test_output(output: &str) -> [(ExamplesStatus, bool); 2] {
+    let mut passed = [0; 2];
+    let mut failed: [Vec<&str>; 2] = Default::default();
+
+    for line in output.lines() {
+        let Some((name, result)) = line
+            .strip_prefix("test ")
+            .and_then(|line| line.split_once(" ... "))
+        else {
+            continue;
+        };
+
+        let Some(part) = part_index(name) else {
+            continue;
+        };
+
+        match result {
+            "ok" => passed[part] += 1,
+            "FAILED" => failed[part].push(name),
+            _ => {}
+        }
+    }
+
+    [0, 1].map(|part| {
+        if !failed[part].is_empty() {
+            let not_implemented = failed[part]
+                .iter()
+                .all(|name| panic_message(output, name).is_some_and(is_not_implemented));
+            (ExamplesStatus::Failed, not_implemented)
+        } else if passed[part] > 0 {
+            (ExamplesStatus::Passed(passed[part]), false)
+        } else {
+            (ExamplesStatus::Missing, false)
+        }
+    })
+}
+
+fn part_index(test_name: &str) -> Option<usize> {
+    match test_name.rsplit("::").next()? {
+        "part_1" => Some(0),
+        "part_2" => Some(1),
+        _ => None,
+    }
+}
+
+/// The captured output of a failed test, which contains its panic message.
+fn panic_message<'a>(output: &'a str, test_name: &str) -> Option<&'a str> {
+    let header = format!("---- {test_name} stdout ----");
+    let start = output.find(&header)? + header.len();
+    let rest = &output[start..];
+    let end = rest
+        .find("\n---- ")
+        .or_else(|| rest.find("\nfailures:"))
+        .unwrap_or(rest.len());
+
+    Some(&rest[..end])
+}
+
+fn is_not_implemented(message: &str) -> bool {
+    message.lines().any(|line| {
+        ["not yet implemented", "not implemented"]
+            .iter()
+            .any(|prefix| {
+                line.strip_prefix(prefix)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with(": "))
+            })
+    })
+}
+
+/// Render the progress as a Markdown table, padded like the formatter of the README does.
+pub fn progress_table(progress: &[DayProgress]) -> String {
+    let header = ["**Day**", "**Stars**", "**Part 1**", "**Part 2**"].map(String::from);
+    let rows: Vec<[String; 4]> = progress
+        .iter()
+        .map(|day| {
+            let parts = if day.exists {
+                day.parts.clone().map(|part| part.to_string())
+            } else {
+                Default::default()
+            };
+            let [part_1, part_2] = parts;
+
+            [
+                format!(
+                    "[Day {:0>2}](https://adventofcode.com/{}/day/{})",
+                    day.day, day.year, day.day
+                ),
+                "⭐".repeat(day.stars()),
+                part_1,
+                part_2,
+            ]
+        })
+        .collect();
+
+    let mut widths = [3; 4];
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(display_width(cell));
+        }
+    }
+
+    let line = |cells: &[String; 4]| {
+        let cells: Vec<_> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell}{}", " ".repeat(width - display_width(cell))))
+            .collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+
+    let mut table = line(&header);
+    table += &line(&widths.map(|width| "-".repeat(width)));
+    for row in &rows {
+        table += &line(row);
+    }
+
+    table
+}
+
+/// The width of the text in a monospace font, where the star emoji takes up two columns.
+fn display_width(text: &str) -> usize {
+    text.chars().map(|c| if c == '⭐' { 2 } else { 1 }).sum()
+}
+
+/// Replace the progress table between the markers in the README.
+pub fn update_readme(path: &Path, table: &str) -> Result<(), ProgressError> {
+    let io_error = |err| ProgressError::Io {
+        path: path.to_path_buf(),
+        err,
+    };
+
+    let readme = fs::read_to_string(path).map_err(io_error)?;
+    let updated = replace_between_markers(&readme, table).ok_or(ProgressError::MissingMarkers {
+        path: path.to_path_buf(),
+    })?;
+
+    if updated != readme {
+        fs::write(path, updated).map_err(io_error)?;
+    }
+
+    Ok(())
+}
+
+fn replace_between_markers(text: &str, table: &str) -> Option<String> {
+    let start = text.find(START_MARKER)? + START_MARKER.len();
+    let end = start + text[start..].find(END_MARKER)?;
+
+    Some(format!("{}\n\n{table}\n{}", &text[..start], &text[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_OUTPUT: &str = "
+running 4 tests
+test tests::example_1::part_1 ... ok
+test tests::test_parse ... ok
+test tests::example_2::part_1 ... ok
+test tests::example_1::part_2 ... FAILED
+
+failures:
+
+---- tests::example_1::part_2 stdout ----
+
+thread 'tests::example_1::part_2' panicked at days/day_10/src/lib.rs:21:9:
+not yet implemented
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    tests::example_1::part_2
+
+test result: FAILED. 3 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+    #[test]
+    fn test_parse_test_output() {
+        assert_eq!(
+            parse_test_output(TEST_OUTPUT),
+            [
+                (ExamplesStatus::Passed(2), false),
+                (ExamplesStatus::Failed, true)
+            ]
+        );
+
+        let wrong_answer = TEST_OUTPUT.replace(
+            "not yet implemented",
+            "assertion `left == right` failed\n  left: 1\n right: 2",
+        );
+        assert_eq!(
+            parse_test_output(&wrong_answer)[1],
+            (ExamplesStatus::Failed, false)
+        );
+
+        assert_eq!(
+            parse_test_output("running 0 tests\n"),
+            [(ExamplesStatus::Missing, false); 2]
+        );
+    }
+
+    #[test]
+    fn test_progress_table() {
+        let progress = [
+            DayProgress {
+                year: 2023,
+                day: 9,
+                exists: true,
+                parts: [
+                    PartProgress {
+                        implemented: Some(true),
+                        examples: ExamplesStatus::Passed(1),
+                        answer: Some(AnswerStatus::Verified),
+                    },
+                    PartProgress {
+                        implemented: Some(false),
+                        examples: ExamplesStatus::Failed,
+                        answer: None,
+                    },
+                ],
+            },
+            DayProgress {
+                year: 2023,
+                day: 10,
+                exists: false,
+                parts: Default::default(),
+            },
+        ];
+
+        assert_eq!(
+            progress_table(&progress),
+            "\
+| **Day**                                        | **Stars** | **Part 1**                 | **Part 2**      |
+| ---------------------------------------------- | --------- | -------------------------- | --------------- |
+| [Day 09](https://adventofcode.com/2023/day/9)  | ⭐        | verified, 1 example passes | not implemented |
+| [Day 10](https://adventofcode.com/2023/day/10) |           |                            |                 |
+"
+        );
+    }
+
+    #[test]
+    fn test_stars() {
+        let part = |implemented, examples, answer| PartProgress {
+            implemented,
+            examples,
+            answer,
+        };
+        let day = |parts| DayProgress {
+            year: 2023,
+            day: 8,
+            exists: true,
+            parts,
+        };
+
+        // No input or answers are needed for a star
+        let passed = part(Some(true), ExamplesStatus::Passed(2), None);
+        assert_eq!(day([passed.clone(), passed.clone()]).stars(), 2);
+
+        let unsolved = part(Some(false), ExamplesStatus::Passed(1), None);
+        let failing = part(None, ExamplesStatus::Failed, Some(AnswerStatus::Verified));
+        assert_eq!(day([unsolved, failing]).stars(), 0);
+
+        let wrong = part(
+            Some(true),
+            ExamplesStatus::Passed(1),
+            Some(AnswerStatus::Wrong {
+                expected: "6".to_string(),
+            }),
+        );
+        assert_eq!(day([passed, wrong]).stars(), 1);
+    }
+
+    #[test]
+    fn test_probe_parts() {
+        let mut parts: [PartProgress; 2] = Default::default();
+        let day_10 = aoc_utils::dyn_day::<day_10::Day10, _, _>();
+        probe_parts(&mut parts, day_10.as_ref(), Duration::from_secs(10));
+
+        assert_eq!(
+            parts.each_ref().map(|part| part.implemented),
+            [Some(false); 2]
+        );
+        assert_eq!(parts[0].to_string(), "not implemented");
+    }
+
+    #[test]
+    fn test_replace_between_markers() {
+        let readme = format!("# Title\n\n{START_MARKER}\nold table\n{END_MARKER}\n\n## License\n");
+
+        assert_eq!(
+            replace_between_markers(&readme, "| new |\n").unwrap(),
+            format!("# Title\n\n{START_MARKER}\n\n| new |\n\n{END_MARKER}\n\n## License\n")
+        );
+        assert_eq!(replace_between_markers("# Title\n", "| new |\n"), None);
+    }
+}
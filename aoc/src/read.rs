@@ -0,0 +1,169 @@
+use std::error::Error;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_utils::{
+    download_puzzle_page, format_countdown, inputs_dir, parse_articles, puzzle_page_path,
+    save_input, saved_session_cookie, select_profile, time_until_unlock, Article, ReqwestClient,
+    SystemClock,
+};
+use clap::Args;
+
+use crate::configured_year;
+
+/// The options for reading puzzle descriptions.
+#[derive(Args, Debug)]
+pub struct ReadArgs {
+    /// The day of the puzzle
+    #[arg(
+        value_parser = clap::value_parser!(u8).range(1..=25),
+        required_unless_present = "export"
+    )]
+    day: Option<u8>,
+
+    /// Show only one part of the puzzle
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Download the description again, e.g. to add the second part after solving the first one
+    #[arg(long)]
+    refresh: bool,
+
+    /// Save the cached descriptions of all days as Markdown files in the directory
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["day", "part", "refresh"])]
+    export: Option<PathBuf>,
+
+    /// The number of columns to wrap the text at
+    #[arg(long, default_value_t = 80)]
+    width: usize,
+
+    /// The year of the event
+    #[arg(long, default_value_t = configured_year())]
+    year: u16,
+
+    /// The URL of the Advent of Code server
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    base_url: String,
+
+    /// Use the session cookie and inputs of another profile than the default one
+    #[arg(long, env = "AOC_PROFILE")]
+    profile: Option<String>,
+}
+
+/// Show the description of a puzzle, or export all cached descriptions.
+pub fn read(args: &ReadArgs) -> ExitCode {
+    if let Some(profile) = &args.profile {
+        if let Err(err) = select_profile(profile) {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let result = match (&args.export, args.day) {
+        (Some(dir), _) => export(args, dir),
+        (None, Some(day)) => show(args, day),
+        (None, None) => unreachable!("clap requires the day without --export"),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Print the puzzle description to the terminal.
+fn show(args: &ReadArgs, day: u8) -> Result<(), Box<dyn Error>> {
+    let articles = load_articles(args, day)?;
+
+    let articles: Vec<_> = match args.part {
+        Some(part) => {
+            let article = articles.get(usize::from(part) - 1).ok_or_else(|| {
+                format!("Part {part} of day {day} is not available yet, it unlocks once the previous part is solved")
+            })?;
+            vec![article]
+        }
+        None => articles.iter().collect(),
+    };
+
+    // Only use colors when the text is shown in a terminal, see https://no-color.org
+    let ansi = io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+    for article in articles {
+        print!("{}", article.to_text(args.width, ansi));
+    }
+
+    Ok(())
+}
+
+/// The parts of the puzzle from the cached page, which is downloaded if it is missing or lacks the part.
+fn load_articles(args: &ReadArgs, day: u8) -> Result<Vec<Article>, Box<dyn Error>> {
+    let page_path = puzzle_page_path(args.year, day);
+    let parts_needed = args.part.map_or(1, usize::from);
+
+    if !args.refresh {
+        if let Ok(page) = fs::read_to_string(&page_path) {
+            let articles = parse_articles(&page);
+            if articles.len() >= parts_needed {
+                return Ok(articles);
+            }
+        }
+    }
+
+    if let Some(left) = time_until_unlock(&SystemClock, args.year, day) {
+        return Err(format!("Day {day} unlocks in {}", format_countdown(left)).into());
+    }
+
+    let page = download_puzzle_page(
+        &ReqwestClient::new(),
+        &args.base_url,
+        args.year,
+        day,
+        saved_session_cookie().as_deref(),
+    )?;
+    save_input(Path::new(&page_path), &page)?;
+    eprintln!("Saved the description to {page_path}");
+
+    Ok(parse_articles(&page))
+}
+
+/// Save the cached descriptions as Markdown files, e.g. `day_08.md`.
+fn export(args: &ReadArgs, dir: &Path) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(dir).map_err(|err| format!("Failed to create {}: {err}", dir.display()))?;
+    let mut exported = 0;
+
+    for day in 1..=25 {
+        let Ok(page) = fs::read_to_string(puzzle_page_path(args.year, day)) else {
+            continue;
+        };
+
+        let mut markdown: String = parse_articles(&page)
+            .iter()
+            .map(|article| article.to_markdown(&args.base_url))
+            .collect();
+        markdown += &format!(
+            "[Day {day} on Advent of Code]({}/{}/day/{day})\n",
+            args.base_url, args.year
+        );
+
+        let path = dir.join(format!("day_{day:0>2}.md"));
+        fs::write(&path, markdown)
+            .map_err(|err| format!("Failed to write {}: {err}", path.display()))?;
+        exported += 1;
+    }
+
+    if exported == 0 {
+        return Err(format!(
+            "No puzzle descriptions are cached in {}/{} yet, read a day with `aoc read <day>` to download it",
+            inputs_dir().display(),
+            args.year
+        )
+        .into());
+    }
+
+    eprintln!("Exported {exported} days to {}", dir.display());
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use aoc_utils::{dyn_day, DynAocDay};
+
+/// The solutions of all days that have been implemented so far.
+pub fn all_days() -> Vec<Box<dyn DynAocDay>> {
+    vec![
+        dyn_day::<day_01::Day01, _, _>(),
+        dyn_day::<day_02::Day02, _, _>(),
+        dyn_day::<day_03::Day03, _, _>(),
+        dyn_day::<day_04::Day04, _, _>(),
+        dyn_day::<day_05::Day05, _, _>(),
+        dyn_day::<day_06::Day06, _, _>(),
+        dyn_day::<day_07::Day07, _, _>(),
+        dyn_day::<day_08::Day08, _, _>(),
+        dyn_day::<day_09::Day09, _, _>(),
+        dyn_day::<day_10::Day10, _, _>(),
+        dyn_day::<day_14::Day14<100>, _, _>(),
+    ]
+}
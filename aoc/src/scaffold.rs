@@ -0,0 +1,408 @@
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The files of a new day crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayTemplate {
+    pub day: u8,
+    /// Whether to add `parser.rs` and `types.rs` modules for parsing the input with nom.
+    pub parser: bool,
+    /// The example from the puzzle description, used in the test stubs.
+    pub example: Option<String>,
+}
+
+/// The reasons why a day crate could not be created.
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The crate of the day exists already.
+    Exists {
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exists { path } => write!(f, "{} exists already", path.display()),
+            Self::Io { path, err } => write!(f, "Failed to access {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl DayTemplate {
+    fn crate_name(&self) -> String {
+        format!("day_{:0>2}", self.day)
+    }
+
+    fn struct_name(&self) -> String {
+        format!("Day{:0>2}", self.day)
+    }
+
+    /// The files of the crate, relative to its directory.
+    pub fn files(&self) -> Vec<(&'static str, String)> {
+        let mut files = vec![
+            ("Cargo.toml", self.cargo_toml()),
+            ("src/main.rs", self.main_rs()),
+            ("src/lib.rs", self.lib_rs()),
+        ];
+
+        if self.parser {
+            files.push(("src/parser.rs", PARSER_RS.to_string()));
+            files.push(("src/types.rs", TYPES_RS.to_string()));
+        }
+
+        files
+    }
+
+    fn cargo_toml(&self) -> String {
+        let nom = if self.parser {
+            "nom = { workspace = true }\n"
+        } else {
+            ""
+        };
+
+        format!(
+            r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+license.workspace = true
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc_utils = {{ path = "../../aoc_utils" }}
+{nom}"#,
+            self.crate_name()
+        )
+    }
+
+    fn main_rs(&self) -> String {
+        format!(
+            "use aoc_utils::AocDay;
+use {}::{};
+
+fn main() {{
+    {}::run();
+}}
+",
+            self.crate_name(),
+            self.struct_name(),
+            self.struct_name()
+        )
+    }
+
+    fn lib_rs(&self) -> String {
+        let name = self.struct_name();
+        let (header, implementation) = if self.parser {
+            (
+                "// The solution is still in progress, so most of the types are not used yet.
+#![allow(dead_code)]
+
+use aoc_utils::AocDay;
+use parser::parse_full_puzzle;
+use types::Puzzle;
+
+mod parser;
+mod types;
+",
+                format!(
+                    "impl AocDay<(), ()> for {name} {{
+    const DAY: u8 = {day};
+
+    type Parsed<'a> = Puzzle;
+
+    fn parse(input: &str) -> Puzzle {{
+        parse_full_puzzle(input)
+    }}
+
+    fn part_1(_input: &Puzzle) {{}}
+
+    fn part_2(_input: &Puzzle) {{}}
+}}",
+                    day = self.day
+                ),
+            )
+        } else {
+            (
+                "use aoc_utils::RawAocDay;\n",
+                format!(
+                    "impl RawAocDay<(), ()> for {name} {{
+    const DAY: u8 = {day};
+
+    fn part_1(_input: &str) {{}}
+
+    fn part_2(_input: &str) {{}}
+}}",
+                    day = self.day
+                ),
+            )
+        };
+
+        // The unit type is the answer of an unsolved part, replace it once the part is solved
+        format!(
+            r#"{header}
+pub struct {name};
+
+{implementation}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const INPUT: &str = {input};
+
+    aoc_utils::examples!({name} {{
+        example: INPUT => {{ part_1: (), part_2: () }},
+    }});
+}}
+"#,
+            input = string_literal(self.example.as_deref().unwrap_or("")),
+        )
+    }
+
+    /// Create the crate in the `days` directory of the workspace.
+    pub fn create(&self, workspace: &Path) -> Result<PathBuf, ScaffoldError> {
+        let dir = workspace.join("days").join(self.crate_name());
+        if dir.exists() {
+            return Err(ScaffoldError::Exists { path: dir });
+        }
+
+        for (file, content) in self.files() {
+            let path = dir.join(file);
+            let write = || {
+                fs::create_dir_all(path.parent().unwrap_or(&dir))?;
+                fs::write(&path, content)
+            };
+
+            write().map_err(|err| ScaffoldError::Io {
+                path: path.clone(),
+                err,
+            })?;
+        }
+
+        Ok(dir)
+    }
+
+    /// Add the crate to the workspace, if its members are listed one by one, and to the runner.
+    ///
+    /// Returns the files that were changed.
+    pub fn register(&self, workspace: &Path) -> Result<Vec<PathBuf>, ScaffoldError> {
+        let crate_name = self.crate_name();
+        let workspace_manifest = workspace.join("Cargo.toml");
+        let runner_manifest = workspace.join("aoc").join("Cargo.toml");
+        let registry = workspace.join("aoc").join("src").join("registry.rs");
+
+        let mut changed = Vec::new();
+
+        if edit_file(&workspace_manifest, |text| {
+            add_workspace_member(text, &crate_name)
+        })? {
+            changed.push(workspace_manifest);
+        }
+
+        let dependency = format!("{crate_name} = {{ path = \"../days/{crate_name}\" }}");
+        if edit_file(&runner_manifest, |text| {
+            insert_sorted_line(text, "day_", &dependency)
+        })? {
+            changed.push(runner_manifest);
+        }
+
+        let entry = format!(
+            "        dyn_day::<{crate_name}::{}, _, _>(),",
+            self.struct_name()
+        );
+        if edit_file(&registry, |text| {
+            insert_sorted_line(text, "        dyn_day::<day_", &entry)
+        })? {
+            changed.push(registry);
+        }
+
+        Ok(changed)
+    }
+}
+
+const PARSER_RS: &str = r#"use nom::{
+    character::complete::{digit1, line_ending},
+    combinator::map_res,
+    multi::separated_list0,
+    IResult, Parser,
+};
+
+use crate::types::Puzzle;
+
+fn parse_num(input: &str) -> IResult<&str, u32> {
+    map_res(digit1, |num: &str| num.parse::<u32>()).parse(input)
+}
+
+fn parse_puzzle(input: &str) -> IResult<&str, Puzzle> {
+    separated_list0(line_ending, parse_num)
+        .map(|numbers| Puzzle { numbers })
+        .parse(input)
+}
+
+pub fn parse_full_puzzle(input: &str) -> Puzzle {
+    let (input, puzzle) = parse_puzzle(input).expect("Failed to parse puzzle");
+    assert_eq!(input, "");
+    puzzle
+}
+"#;
+
+const TYPES_RS: &str = r#"#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub numbers: Vec<u32>,
+}
+"#;
+
+/// Apply the edit to the file, returning whether it changed.
+fn edit_file(
+    path: &Path,
+    edit: impl FnOnce(&str) -> Option<String>,
+) -> Result<bool, ScaffoldError> {
+    let io_error = |err| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        err,
+    };
+
+    let text = fs::read_to_string(path).map_err(io_error)?;
+    match edit(&text) {
+        Some(updated) => {
+            fs::write(path, updated).map_err(io_error)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Write the text as a Rust string literal, keeping its line breaks.
+fn string_literal(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Insert a line into the block of lines starting with the prefix, keeping the block sorted.
+///
+/// Returns `None` if the line is present already or there is no such block.
+fn insert_sorted_line(text: &str, prefix: &str, line: &str) -> Option<String> {
+    let lines: Vec<_> = text.lines().collect();
+    if lines.contains(&line) {
+        return None;
+    }
+
+    let block: Vec<_> = (0..lines.len())
+        .filter(|&idx| lines[idx].starts_with(prefix))
+        .collect();
+    let (&first, &last) = (block.first()?, block.last()?);
+    let idx = block
+        .into_iter()
+        .find(|&idx| lines[idx] > line)
+        .unwrap_or(last + 1)
+        .max(first);
+
+    let mut lines: Vec<_> = lines.into_iter().map(str::to_string).collect();
+    lines.insert(idx, line.to_string());
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Add the crate to the members of the workspace, unless they include all crates in `days/`.
+fn add_workspace_member(text: &str, crate_name: &str) -> Option<String> {
+    let member = format!("\"days/{crate_name}\"");
+    if text.contains("\"days/*\"") || text.contains(&member) {
+        return None;
+    }
+
+    let start = text.find("members = [")? + "members = [".len();
+    let end = start + text[start..].find(']')?;
+    let members = text[start..end].trim_end().trim_end_matches(',');
+
+    Some(format!(
+        "{}{members}, {member}{}",
+        &text[..start],
+        &text[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_sorted_line() {
+        let registry = "    vec![
+        dyn_day::<day_09::Day09, _, _>(),
+        dyn_day::<day_10::Day10, _, _>(),
+        dyn_day::<day_14::Day14<100>, _, _>(),
+    ]
+";
+
+        assert_eq!(
+            insert_sorted_line(
+                registry,
+                "        dyn_day::<day_",
+                "        dyn_day::<day_11::Day11, _, _>(),"
+            )
+            .unwrap(),
+            "    vec![
+        dyn_day::<day_09::Day09, _, _>(),
+        dyn_day::<day_10::Day10, _, _>(),
+        dyn_day::<day_11::Day11, _, _>(),
+        dyn_day::<day_14::Day14<100>, _, _>(),
+    ]
+"
+        );
+        assert_eq!(
+            insert_sorted_line(
+                registry,
+                "        dyn_day::<day_",
+                "        dyn_day::<day_10::Day10, _, _>(),"
+            ),
+            None
+        );
+
+        let manifest = "clap = { workspace = true }\nday_01 = { path = \"../days/day_01\" }\n";
+        assert_eq!(
+            insert_sorted_line(manifest, "day_", "day_25 = { path = \"../days/day_25\" }")
+                .unwrap(),
+            "clap = { workspace = true }\nday_01 = { path = \"../days/day_01\" }\nday_25 = { path = \"../days/day_25\" }\n"
+        );
+    }
+
+    #[test]
+    fn test_add_workspace_member() {
+        assert_eq!(
+            add_workspace_member("members = [\"aoc\", \"aoc_utils\", \"days/*\"]\n", "day_11"),
+            None
+        );
+        assert_eq!(
+            add_workspace_member("members = [\"aoc\", \"days/day_01\"]\n", "day_11").unwrap(),
+            "members = [\"aoc\", \"days/day_01\", \"days/day_11\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_day_template() {
+        let template = DayTemplate {
+            day: 11,
+            parser: false,
+            example: Some("...#\n#\"..".to_string()),
+        };
+        let files = template.files();
+        let lib = &files
+            .iter()
+            .find(|(file, _)| *file == "src/lib.rs")
+            .unwrap()
+            .1;
+
+        assert_eq!(files.len(), 3);
+        assert!(lib.contains("impl RawAocDay<(), ()> for Day11 {"));
+        assert!(lib.contains("const INPUT: &str = \"...#\n#\\\"..\";"));
+    }
+}
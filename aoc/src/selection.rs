@@ -0,0 +1,60 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// The days selected on the command line, e.g. `5`, `3..9` or `3..=9`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(RangeInclusive<u8>);
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            day.trim()
+                .parse::<u8>()
+                .map_err(|_| format!("Invalid day {day:?}"))
+        };
+
+        let range = if let Some((start, end)) = s.split_once("..=") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            let end = parse_day(end)?;
+
+            if end == 0 {
+                return Err(format!("The range {s} does not contain any days"));
+            }
+
+            parse_day(start)?..=end - 1
+        } else {
+            let day = parse_day(s)?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            Err(format!("The range {s} does not contain any days"))
+        } else {
+            Ok(Self(range))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_day_selection() {
+        assert_eq!("5".parse(), Ok(DaySelection(5..=5)));
+        assert_eq!("3..9".parse(), Ok(DaySelection(3..=8)));
+        assert_eq!("3..=9".parse(), Ok(DaySelection(3..=9)));
+        assert!("9..3".parse::<DaySelection>().is_err());
+        assert!("3..0".parse::<DaySelection>().is_err());
+        assert!("five".parse::<DaySelection>().is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use std::io::{self, IsTerminal};
+use std::path::Path;
+use std::process::ExitCode;
+
+use aoc_utils::{
+    download_with_retries, format_countdown, get_session_cookie, input_file_path,
+    mark_session_expired, select_profile, time_until_unlock, wait_for_unlock, DownloadError,
+    ReqwestClient, SystemClock,
+};
+use clap::Args;
+
+use crate::configured_year;
+
+/// The options for waiting for a puzzle to unlock.
+#[derive(Args, Debug)]
+pub struct WaitArgs {
+    /// The day of the puzzle
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The year of the event
+    #[arg(long, default_value_t = configured_year())]
+    year: u16,
+
+    /// The URL of the Advent of Code server
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    base_url: String,
+
+    /// Use the session cookie and inputs of another profile than the default one
+    #[arg(long, env = "AOC_PROFILE")]
+    profile: Option<String>,
+
+    /// Never ask for the session cookie, fail instead (implied if stdin is not a terminal)
+    #[arg(long)]
+    no_prompt: bool,
+}
+
+impl WaitArgs {
+    /// Whether the user may be asked for the session cookie.
+    fn interactive(&self) -> bool {
+        !self.no_prompt && io::stdin().is_terminal()
+    }
+}
+
+/// Count down to the unlock of the puzzle, then download its input.
+pub fn wait(args: &WaitArgs) -> ExitCode {
+    if let Some(profile) = &args.profile {
+        if let Err(err) = select_profile(profile) {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let file_path = input_file_path(args.year, args.day);
+    if Path::new(&file_path).exists() {
+        eprintln!("The input is already saved in {file_path}");
+        return ExitCode::SUCCESS;
+    }
+
+    // Ask for the session cookie now, rather than when the puzzle unlocks
+    let session_cookie = match get_session_cookie(args.interactive()) {
+        Ok(session_cookie) => session_cookie,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let clock = SystemClock;
+    if time_until_unlock(&clock, args.year, args.day).is_some() {
+        wait_for_unlock(&clock, args.year, args.day, |left| {
+            eprint!("\rDay {} unlocks in {}", args.day, format_countdown(left));
+        });
+        eprintln!();
+    }
+
+    let input = download_with_retries(
+        &clock,
+        &ReqwestClient::new(),
+        &args.base_url,
+        args.year,
+        args.day,
+        &session_cookie,
+        Path::new(&file_path),
+    );
+
+    match input {
+        Ok(input) => {
+            eprintln!(
+                "Saved the input to {file_path} ({} lines)",
+                input.lines().count()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            if matches!(err, DownloadError::SessionExpired) {
+                mark_session_expired();
+            }
+
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
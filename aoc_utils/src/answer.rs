@@ -0,0 +1,68 @@
+use std::fmt::Debug;
+
+/// A type that can be the answer to a part of the puzzle.
+///
+/// The answer has a canonical form, which is submitted to the server and stored as the known answer,
+/// and a form for humans, which may e.g. span several lines for answers drawn in ASCII art.
+pub trait AocAnswer: Eq + Debug {
+    /// The answer as it is submitted and stored, or `None` if the part hasn't been solved yet.
+    fn submission(&self) -> Option<String>;
+
+    /// The answer as it is shown to humans.
+    fn display(&self) -> String {
+        self.submission()
+            .unwrap_or_else(|| "not yet solved".to_string())
+    }
+}
+
+macro_rules! impl_aoc_answer_for_integers {
+    ($($int:ty),*) => {
+        $(
+            impl AocAnswer for $int {
+                fn submission(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_aoc_answer_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl AocAnswer for String {
+    fn submission(&self) -> Option<String> {
+        Some(self.trim().to_string())
+    }
+
+    fn display(&self) -> String {
+        self.trim_end().to_string()
+    }
+}
+
+/// The answer of a part that hasn't been solved yet.
+impl AocAnswer for () {
+    fn submission(&self) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aoc_answer() {
+        assert_eq!(42u32.submission(), Some("42".to_string()));
+        assert_eq!((-7i64).display(), "-7");
+
+        let text = "qqfl\n".to_string();
+        assert_eq!(text.submission(), Some("qqfl".to_string()));
+        assert_eq!(text.display(), "qqfl");
+
+        let art = "#..#\n####\n".to_string();
+        assert_eq!(art.display(), "#..#\n####");
+
+        assert_eq!(().submission(), None);
+        assert_eq!(().display(), "not yet solved");
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::submit::WrongGuess;
+
+/// The name of the file storing the known answers, next to the input files.
+const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+/// The known answers for the inputs in one directory.
+///
+/// The answers are stored in an `answers.toml` file next to the inputs,
+/// with one table per input file:
+///
+/// ```toml
+/// ["day_05.txt"]
+/// part_1 = "35"
+/// part_2 = "46"
+///
+/// [["day_05.txt".wrong_part_2]]
+/// answer = "50"
+/// verdict = "too_high"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    inputs: BTreeMap<String, KnownAnswers>,
+}
+
+/// The known answers for a single input.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_2: Option<String>,
+    /// The answers for part 1 that have been rejected when submitting them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    wrong_part_1: Vec<WrongGuess>,
+    /// The answers for part 2 that have been rejected when submitting them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    wrong_part_2: Vec<WrongGuess>,
+}
+
+impl KnownAnswers {
+    fn part(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => panic!("Invalid puzzle part {part}"),
+        }
+    }
+
+    fn part_mut(&mut self, part: u8) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part_1,
+            2 => &mut self.part_2,
+            _ => panic!("Invalid puzzle part {part}"),
+        }
+    }
+
+    fn wrong_guesses(&self, part: u8) -> &[WrongGuess] {
+        match part {
+            1 => &self.wrong_part_1,
+            2 => &self.wrong_part_2,
+            _ => panic!("Invalid puzzle part {part}"),
+        }
+    }
+
+    fn wrong_guesses_mut(&mut self, part: u8) -> &mut Vec<WrongGuess> {
+        match part {
+            1 => &mut self.wrong_part_1,
+            2 => &mut self.wrong_part_2,
+            _ => panic!("Invalid puzzle part {part}"),
+        }
+    }
+}
+
+/// How an answer compares to the known answer for the input.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum AnswerStatus {
+    /// The answer matches the known answer.
+    Verified,
+
+    /// The answer differs from the known answer.
+    Wrong { expected: String },
+
+    /// There is no known answer for the input yet.
+    #[default]
+    Unknown,
+}
+
+impl Display for AnswerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Verified => write!(f, "verified"),
+            Self::Wrong { expected } => write!(f, "WRONG, expected {expected}"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// The reasons why the known answers could not be loaded or saved.
+#[derive(Debug)]
+pub enum AnswerStoreError {
+    Io { path: PathBuf, err: io::Error },
+    Parse { path: PathBuf, err: toml::de::Error },
+}
+
+impl Display for AnswerStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, err } => write!(f, "Failed to access {}: {err}", path.display()),
+            Self::Parse { path, err } => write!(f, "Failed to parse {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for AnswerStoreError {}
+
+impl AnswerStore {
+    /// The path of the answer store responsible for the given input file.
+    pub fn path_for_input(input_path: &Path) -> PathBuf {
+        input_path
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join(ANSWERS_FILE_NAME)
+    }
+
+    /// Load the answer store from the given path.
+    ///
+    /// If the file doesn't exist yet, the store is empty.
+    pub fn load(path: &Path) -> Result<Self, AnswerStoreError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path).map_err(|err| AnswerStoreError::Io {
+            path: path.to_path_buf(),
+            err,
+        })?;
+
+        toml::from_str(&content).map_err(|err| AnswerStoreError::Parse {
+            path: path.to_path_buf(),
+            err,
+        })
+    }
+
+    /// Save the answer store to the given path.
+    pub fn save(&self, path: &Path) -> Result<(), AnswerStoreError> {
+        let content = toml::to_string(self).expect("Failed to serialize answers");
+
+        fs::write(path, content).map_err(|err| AnswerStoreError::Io {
+            path: path.to_path_buf(),
+            err,
+        })
+    }
+
+    /// The known answer for a part of the puzzle with the given input.
+    pub fn get(&self, input_name: &str, part: u8) -> Option<&String> {
+        self.inputs
+            .get(input_name)
+            .and_then(|answers| answers.part(part))
+    }
+
+    /// Compare the answer against the known answer for the input.
+    pub fn check(&self, input_name: &str, part: u8, answer: &str) -> AnswerStatus {
+        match self.get(input_name, part) {
+            Some(expected) if expected == answer => AnswerStatus::Verified,
+            Some(expected) => AnswerStatus::Wrong {
+                expected: expected.clone(),
+            },
+            None => AnswerStatus::Unknown,
+        }
+    }
+
+    /// Record the answer as the known answer for the input.
+    pub fn accept(&mut self, input_name: &str, part: u8, answer: &str) {
+        *self
+            .inputs
+            .entry(input_name.to_string())
+            .or_default()
+            .part_mut(part) = Some(answer.to_string());
+    }
+
+    /// The earlier guess that proves that the answer is wrong, if any.
+    pub fn ruled_out_by(&self, input_name: &str, part: u8, answer: &str) -> Option<&WrongGuess> {
+        self.inputs
+            .get(input_name)?
+            .wrong_guesses(part)
+            .iter()
+            .find(|guess| guess.rules_out(answer))
+    }
+
+    /// Remember a guess that has been rejected by the server.
+    pub fn add_wrong_guess(&mut self, input_name: &str, part: u8, guess: WrongGuess) {
+        self.inputs
+            .entry(input_name.to_string())
+            .or_default()
+            .wrong_guesses_mut(part)
+            .push(guess);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SubmitOutcome;
+
+    #[test]
+    fn test_answer_store_check() {
+        let store: AnswerStore = toml::from_str(
+            r#"["day_05.txt"]
+part_1 = "35"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(store.check("day_05.txt", 1, "35"), AnswerStatus::Verified);
+        assert_eq!(
+            store.check("day_05.txt", 1, "36"),
+            AnswerStatus::Wrong {
+                expected: "35".to_string()
+            }
+        );
+        assert_eq!(store.check("day_05.txt", 2, "46"), AnswerStatus::Unknown);
+        assert_eq!(store.check("day_06.txt", 1, "35"), AnswerStatus::Unknown);
+    }
+
+    #[test]
+    fn test_answer_store_wrong_guesses() {
+        let mut store = AnswerStore::default();
+        store.add_wrong_guess(
+            "day_05.txt",
+            1,
+            WrongGuess::new("40", SubmitOutcome::TooHigh).unwrap(),
+        );
+
+        assert_eq!(store.ruled_out_by("day_05.txt", 1, "35"), None);
+        assert_eq!(
+            store.ruled_out_by("day_05.txt", 1, "41"),
+            Some(&WrongGuess::new("40", SubmitOutcome::TooHigh).unwrap())
+        );
+        assert_eq!(store.ruled_out_by("day_05.txt", 2, "41"), None);
+
+        assert_eq!(
+            toml::to_string(&store).unwrap(),
+            r#"[["day_05.txt".wrong_part_1]]
+answer = "40"
+verdict = "too_high"
+"#
+        );
+    }
+
+    #[test]
+    fn test_answer_store_accept() {
+        let mut store = AnswerStore::default();
+        store.accept("day_05.txt", 2, "46");
+        store.accept("day_05.txt", 1, "35");
+
+        assert_eq!(
+            toml::to_string(&store).unwrap(),
+            r#"["day_05.txt"]
+part_1 = "35"
+part_2 = "46"
+"#
+        );
+    }
+}
@@ -0,0 +1,599 @@
+use crate::html::decode_entities;
+
+/// A part of a puzzle description, i.e. one `<article class="day-desc">` of the puzzle page.
+///
+/// Only the markup used in the descriptions is kept: headings, paragraphs, lists and code blocks,
+/// with emphasis, inline code and links inside of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Article {
+    blocks: Vec<Block>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Block {
+    Heading(String),
+    Paragraph(Vec<Span>),
+    /// A `<pre><code>` block, where emphasis highlights parts of the example.
+    Code(Vec<Span>),
+    List(Vec<Vec<Span>>),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Style {
+    emphasis: bool,
+    code: bool,
+    link: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Span {
+    text: String,
+    style: Style,
+}
+
+/// The block that is being parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OpenBlock {
+    Heading,
+    Paragraph,
+    Code,
+    Item,
+}
+
+#[derive(Default)]
+struct Parser {
+    blocks: Vec<Block>,
+    open: Option<(OpenBlock, Vec<Span>)>,
+    items: Option<Vec<Vec<Span>>>,
+    emphasis: usize,
+    code: usize,
+    link: Option<String>,
+}
+
+impl Parser {
+    fn start(&mut self, block: OpenBlock) {
+        self.finish();
+        self.open = Some((block, Vec::new()));
+    }
+
+    fn finish(&mut self) {
+        let Some((block, spans)) = self.open.take() else {
+            return;
+        };
+
+        match (block, &mut self.items) {
+            (OpenBlock::Heading, _) => {
+                let text: String = spans.into_iter().map(|span| span.text).collect();
+                self.blocks.push(Block::Heading(
+                    collapse_whitespace(&text).trim().to_string(),
+                ));
+            }
+            (OpenBlock::Code, _) => self.blocks.push(Block::Code(spans)),
+            (OpenBlock::Item, Some(items)) => items.push(spans),
+            (OpenBlock::Paragraph | OpenBlock::Item, _) => {
+                if spans.iter().any(|span| !span.text.trim().is_empty()) {
+                    self.blocks.push(Block::Paragraph(spans));
+                }
+            }
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.open.is_none() {
+            if text.trim().is_empty() {
+                return;
+            }
+            self.start(OpenBlock::Paragraph);
+        }
+
+        let Some((block, spans)) = &mut self.open else {
+            return;
+        };
+        let style = Style {
+            emphasis: self.emphasis > 0,
+            code: self.code > 0 && *block != OpenBlock::Code,
+            link: self.link.clone(),
+        };
+        let text = decode_entities(text);
+        if text.is_empty() {
+            return;
+        }
+
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.text += &text,
+            _ => spans.push(Span { text, style }),
+        }
+    }
+
+    fn tag(&mut self, tag: &str) {
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("h1" | "h2" | "h3", false) => self.start(OpenBlock::Heading),
+            ("p", false) => self.start(OpenBlock::Paragraph),
+            ("pre", false) => self.start(OpenBlock::Code),
+            ("li", false) => self.start(OpenBlock::Item),
+            ("h1" | "h2" | "h3" | "p" | "pre" | "li", true) => self.finish(),
+            ("ul" | "ol", false) => {
+                self.finish();
+                self.items = Some(Vec::new());
+            }
+            ("ul" | "ol", true) => {
+                self.finish();
+                if let Some(items) = self.items.take() {
+                    self.blocks.push(Block::List(items));
+                }
+            }
+            ("em", false) => self.emphasis += 1,
+            ("em", true) => self.emphasis = self.emphasis.saturating_sub(1),
+            ("code", false) => self.code += 1,
+            ("code", true) => self.code = self.code.saturating_sub(1),
+            ("a", false) => self.link = attribute(tag, "href"),
+            ("a", true) => self.link = None,
+            _ => {}
+        }
+    }
+
+    fn parse(mut self, html: &str) -> Article {
+        let mut rest = html;
+
+        while let Some(start) = rest.find('<') {
+            self.text(&rest[..start]);
+
+            let Some(end) = rest[start..].find('>') else {
+                rest = &rest[start..];
+                break;
+            };
+            self.tag(&rest[start + 1..start + end]);
+            rest = &rest[start + end + 1..];
+        }
+
+        self.text(rest);
+        self.finish();
+
+        Article {
+            blocks: self.blocks,
+        }
+    }
+}
+
+/// The value of an attribute of a tag, e.g. the `href` of `a href="/2023/day/8/input"`.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+
+    Some(decode_entities(&tag[start..end]))
+}
+
+/// Replace each run of whitespace by a single space, like HTML does outside of `<pre>` blocks.
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut space = false;
+
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            space = true;
+        } else {
+            if space {
+                collapsed.push(' ');
+                space = false;
+            }
+            collapsed.push(ch);
+        }
+    }
+
+    if space {
+        collapsed.push(' ');
+    }
+    collapsed
+}
+
+/// Extract the parts of the puzzle from its description page.
+///
+/// The page only contains the second part once the first part has been solved.
+pub fn parse_articles(html: &str) -> Vec<Article> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        rest = &rest[start..];
+        let Some(content_start) = rest.find('>') else {
+            break;
+        };
+        let end = rest.find("</article>").unwrap_or(rest.len());
+
+        articles.push(Parser::default().parse(&rest[content_start + 1..end]));
+        rest = &rest[end..];
+    }
+
+    articles
+}
+
+/// The escape codes of the styles in a terminal.
+fn ansi_codes(style: &Style) -> Option<String> {
+    let codes: Vec<_> = [
+        (style.emphasis, "1"),
+        (style.code, "36"),
+        (style.link.is_some(), "4"),
+    ]
+    .into_iter()
+    .filter_map(|(enabled, code)| enabled.then_some(code))
+    .collect();
+
+    (!codes.is_empty()).then(|| format!("\x1b[{}m", codes.join(";")))
+}
+
+/// Write the text with the style, using escape codes or, without them, Markdown-like markers.
+fn push_styled(out: &mut String, text: &str, style: &Style, ansi: bool) {
+    if ansi {
+        match ansi_codes(style) {
+            Some(codes) => *out += &format!("{codes}{text}\x1b[0m"),
+            None => *out += text,
+        }
+        return;
+    }
+
+    let (start, end) = markers(style);
+    *out += &format!("{start}{text}{end}");
+}
+
+/// The Markdown-like markers around text with the style, when escape codes aren't used.
+fn markers(style: &Style) -> (&'static str, &'static str) {
+    match (style.emphasis, style.code) {
+        (true, true) => ("*`", "`*"),
+        (true, false) => ("*", "*"),
+        (false, true) => ("`", "`"),
+        (false, false) => ("", ""),
+    }
+}
+
+/// The number of characters that the runs of a line take up in the terminal.
+///
+/// Escape codes take up no space, but the markers that replace them do.
+fn line_width(line: &[(String, Style)], ansi: bool) -> usize {
+    line.iter()
+        .map(|(text, style)| {
+            let (start, end) = if ansi { ("", "") } else { markers(style) };
+            start.len() + text.chars().count() + end.len()
+        })
+        .sum()
+}
+
+/// Add the text to the line, joining it with the last run if they have the same style.
+fn push_run(line: &mut Vec<(String, Style)>, text: &str, style: &Style) {
+    match line.last_mut() {
+        Some((last, last_style)) if last_style == style => *last += text,
+        _ => line.push((text.to_string(), style.clone())),
+    }
+}
+
+/// Break the text of the spans into lines of at most `width` characters, where possible.
+///
+/// Each line is a list of runs of text with the same style.
+/// Without `ansi`, the markers of the styles count towards the width.
+fn wrap(spans: &[Span], width: usize, ansi: bool) -> Vec<Vec<(String, Style)>> {
+    // The words, made up of pieces with different styles, e.g. `<code>x</code>,`
+    let mut words: Vec<Vec<(String, &Style)>> = vec![Vec::new()];
+    for span in spans {
+        for ch in span.text.chars() {
+            let word = words.last_mut().expect("There is always a word");
+
+            if ch.is_whitespace() {
+                if !word.is_empty() {
+                    words.push(Vec::new());
+                }
+            } else {
+                match word.last_mut() {
+                    Some((text, style)) if *style == &span.style => text.push(ch),
+                    _ => word.push((ch.to_string(), &span.style)),
+                }
+            }
+        }
+    }
+    words.retain(|word| !word.is_empty());
+
+    let mut lines: Vec<Vec<(String, Style)>> = Vec::new();
+    for word in words {
+        // The runs are joined when the styles match, so the width is only known once the word is added
+        if let Some(line) = lines.last_mut() {
+            let mut joined = line.clone();
+            // The space between two words keeps their style if they share it
+            let space_style = match (joined.last(), word.first()) {
+                (Some((_, before)), Some((_, after))) if before == *after => before.clone(),
+                _ => Style::default(),
+            };
+            push_run(&mut joined, " ", &space_style);
+            for (text, style) in &word {
+                push_run(&mut joined, text, style);
+            }
+
+            if line_width(&joined, ansi) <= width {
+                *line = joined;
+                continue;
+            }
+        }
+
+        let mut line = Vec::new();
+        for (text, style) in word {
+            push_run(&mut line, &text, style);
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+impl Article {
+    /// Render the article as text for a terminal, wrapped to the given width.
+    ///
+    /// With `ansi`, emphasis, inline code and links are shown with escape codes,
+    /// otherwise with Markdown-like markers, which count towards the width.
+    pub fn to_text(&self, width: usize, ansi: bool) -> String {
+        let mut out = String::new();
+        let push_lines = |out: &mut String, spans: &[Span], first: &str, indent: &str| {
+            let width = width.saturating_sub(first.chars().count()).max(1);
+
+            for (idx, line) in wrap(spans, width, ansi).into_iter().enumerate() {
+                *out += if idx == 0 { first } else { indent };
+                for (text, style) in line {
+                    push_styled(out, &text, &style, ansi);
+                }
+                *out += "\n";
+            }
+        };
+
+        for block in &self.blocks {
+            match block {
+                Block::Heading(heading) if ansi => out += &format!("\x1b[1m{heading}\x1b[0m\n"),
+                Block::Heading(heading) => out += &format!("{heading}\n"),
+                Block::Paragraph(spans) => push_lines(&mut out, spans, "", ""),
+                Block::Code(spans) => {
+                    // The examples are indented and kept as they are, highlights only with escape codes
+                    let mut code = String::new();
+                    for span in spans {
+                        if ansi {
+                            push_styled(&mut code, &span.text, &span.style, true);
+                        } else {
+                            code += &span.text;
+                        }
+                    }
+                    for line in code.trim_end_matches('\n').lines() {
+                        out += format!("    {line}").trim_end();
+                        out += "\n";
+                    }
+                }
+                Block::List(items) => {
+                    for item in items {
+                        push_lines(&mut out, item, "  - ", "    ");
+                    }
+                }
+            }
+            out += "\n";
+        }
+
+        out
+    }
+
+    /// Render the article as Markdown, with relative links resolved against the base URL.
+    pub fn to_markdown(&self, base_url: &str) -> String {
+        let mut out = String::new();
+
+        for block in &self.blocks {
+            match block {
+                Block::Heading(heading) => {
+                    let heading = heading.trim_matches(|c: char| c == '-' || c.is_whitespace());
+                    out += &format!("## {}\n", escape_markdown(heading));
+                }
+                Block::Paragraph(spans) => {
+                    out += markdown_spans(spans, base_url).trim();
+                    out += "\n";
+                }
+                Block::Code(spans) => {
+                    let code: String = spans.iter().map(|span| span.text.as_str()).collect();
+                    out += &format!("```\n{}\n```\n", code.trim_end_matches('\n'));
+                }
+                Block::List(items) => {
+                    for item in items {
+                        out += &format!("- {}\n", markdown_spans(item, base_url).trim());
+                    }
+                }
+            }
+            out += "\n";
+        }
+
+        out
+    }
+}
+
+fn markdown_spans(spans: &[Span], base_url: &str) -> String {
+    let mut out = String::new();
+
+    for span in spans {
+        let text = collapse_whitespace(&span.text);
+        // The markers must be next to the text, so the surrounding whitespace goes outside of them
+        let inner = text.trim();
+        if inner.is_empty() {
+            out += &text;
+            continue;
+        }
+        let leading = &text[..text.len() - text.trim_start().len()];
+        let trailing = &text[text.trim_end().len()..];
+
+        let mut styled = if span.style.code {
+            format!("`{inner}`")
+        } else {
+            escape_markdown(inner)
+        };
+        if span.style.emphasis {
+            styled = format!("*{styled}*");
+        }
+        if let Some(link) = &span.style.link {
+            let link = if link.starts_with('/') {
+                format!("{base_url}{link}")
+            } else {
+                link.clone()
+            };
+            styled = format!("[{styled}]({link})");
+        }
+
+        out += &format!("{leading}{styled}{trailing}");
+    }
+
+    out
+}
+
+/// Escape the characters that would otherwise be read as Markdown.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for ch in text.chars() {
+        if matches!(ch, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 8: Haunted Wasteland ---</h2><p>You're still riding a camel across Desert Island when you spot a sandstorm quickly approaching.</p>
+<p>It seems like you're meant to use the <em>left/right</em> instructions to <em>navigate the network</em>. For example:</p>
+<pre><code>RL
+
+AAA = (BBB, CCC)
+<em>ZZZ</em> = (ZZZ, ZZZ)
+</code></pre>
+<ul>
+<li>Starting at <code>AAA</code>, you need to look up the next element.</li>
+<li>Get your <a href="/2023/day/8/input" target="_blank">puzzle input</a>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>12345</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>How many steps does it take before you're only on nodes that end with <code><em>Z</em></code>?</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_parse_articles() {
+        let articles = parse_articles(PAGE);
+
+        assert_eq!(articles.len(), 2);
+        assert_eq!(
+            articles[1].blocks,
+            [
+                Block::Heading("--- Part Two ---".to_string()),
+                Block::Paragraph(vec![
+                    Span {
+                        text:
+                            "How many steps does it take before you're only on nodes that end with "
+                                .to_string(),
+                        style: Style::default(),
+                    },
+                    Span {
+                        text: "Z".to_string(),
+                        style: Style {
+                            emphasis: true,
+                            code: true,
+                            link: None,
+                        },
+                    },
+                    Span {
+                        text: "?".to_string(),
+                        style: Style::default(),
+                    },
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_text() {
+        let articles = parse_articles(PAGE);
+
+        assert_eq!(
+            articles[0].to_text(40, false),
+            "--- Day 8: Haunted Wasteland ---
+
+You're still riding a camel across
+Desert Island when you spot a sandstorm
+quickly approaching.
+
+It seems like you're meant to use the
+*left/right* instructions to *navigate*
+*the network*. For example:
+
+    RL
+
+    AAA = (BBB, CCC)
+    ZZZ = (ZZZ, ZZZ)
+
+  - Starting at `AAA`, you need to look
+    up the next element.
+  - Get your puzzle input.
+
+"
+        );
+
+        let text = articles[1].to_text(80, true);
+        assert!(text.contains("end with \x1b[1;36mZ\x1b[0m?"));
+    }
+
+    #[test]
+    fn test_to_text_width_with_markers() {
+        let articles = parse_articles(PAGE);
+
+        // The headings and code blocks aren't wrapped, so the widths start at their length
+        for width in 40..=80 {
+            for article in &articles {
+                let text = article.to_text(width, false);
+                for line in text.lines() {
+                    assert!(
+                        line.chars().count() <= width,
+                        "{line:?} is longer than {width}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let articles = parse_articles(PAGE);
+        let markdown = articles[0].to_markdown("https://adventofcode.com");
+
+        assert!(markdown.starts_with("## Day 8: Haunted Wasteland\n\n"));
+        assert!(markdown.contains(
+            "use the *left/right* instructions to *navigate the network*. For example:\n"
+        ));
+        assert!(markdown.contains("```\nRL\n\nAAA = (BBB, CCC)\nZZZ = (ZZZ, ZZZ)\n```\n"));
+        assert!(markdown.contains("- Starting at `AAA`, you need to look up the next element.\n"));
+        assert!(markdown
+            .contains("- Get your [puzzle input](https://adventofcode.com/2023/day/8/input).\n"));
+
+        assert_eq!(
+            articles[1].to_markdown("https://adventofcode.com"),
+            "## Part Two\n\nHow many steps does it take before you're only on nodes that end with *`Z`*?\n\n"
+        );
+    }
+
+    #[test]
+    fn test_escape_markdown() {
+        assert_eq!(
+            escape_markdown("a*b_c [d] <e>"),
+            "a\\*b\\_c \\[d\\] \\<e\\>"
+        );
+    }
+}
@@ -0,0 +1,454 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::bench::BenchStats;
+
+/// The directory storing the baselines, inside the cargo target directory.
+const BASELINES_DIR: &str = "aoc/baselines";
+
+/// The p-value below which a change is considered statistically significant.
+const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// The benchmark results of a previous run, to compare later runs against.
+///
+/// A baseline is stored as JSON in `target/aoc/baselines/<name>.json`,
+/// with one entry per day, so several days can be saved to the same baseline.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// The results per day and input, e.g. `2023/day_05:inputs/2023/day_05.txt`.
+    pub days: BTreeMap<String, DayBaseline>,
+}
+
+/// The benchmark results of a single day, and where they come from.
+///
+/// The days of a baseline can be saved at different times, so each day records its own origin.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBaseline {
+    pub input_path: String,
+    /// The machine that the results were recorded on.
+    #[serde(default)]
+    pub machine: Option<MachineInfo>,
+    /// The git revision that the results were recorded from.
+    #[serde(default)]
+    pub git_revision: Option<String>,
+    /// When the results were recorded, in seconds since the Unix epoch.
+    #[serde(default)]
+    pub updated_at: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part_1_times_ns: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part_2_times_ns: Vec<u64>,
+}
+
+/// The machine that a baseline was recorded on, to know whether the timings are comparable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MachineInfo {
+    pub os: String,
+    pub arch: String,
+    pub cpus: usize,
+    pub cpu_model: Option<String>,
+}
+
+impl MachineInfo {
+    /// The information about the current machine.
+    pub fn current() -> Self {
+        Self {
+            os: env::consts::OS.to_string(),
+            arch: env::consts::ARCH.to_string(),
+            cpus: thread::available_parallelism().map_or(1, |cpus| cpus.get()),
+            cpu_model: fs::read_to_string("/proc/cpuinfo").ok().and_then(|info| {
+                info.lines()
+                    .find_map(|line| line.strip_prefix("model name"))
+                    .and_then(|line| line.split_once(':'))
+                    .map(|(_, model)| model.trim().to_string())
+            }),
+        }
+    }
+}
+
+/// The reasons why a baseline could not be loaded or saved.
+#[derive(Debug)]
+pub enum BaselineError {
+    /// There is no baseline with this name yet.
+    Missing {
+        name: String,
+    },
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        err: serde_json::Error,
+    },
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { name } => write!(
+                f,
+                "The baseline {name:?} does not exist, create it with --save-baseline {name}"
+            ),
+            Self::Io { path, err } => write!(f, "Failed to access {}: {err}", path.display()),
+            Self::Parse { path, err } => write!(f, "Failed to parse {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+impl Baseline {
+    /// The path of the baseline with the given name.
+    pub fn path(name: &str) -> PathBuf {
+        let target_dir = env::var_os("CARGO_TARGET_DIR").unwrap_or("target".into());
+        Path::new(&target_dir)
+            .join(BASELINES_DIR)
+            .join(format!("{name}.json"))
+    }
+
+    /// Load the baseline from the given path, if it exists.
+    pub fn load(path: &Path) -> Result<Option<Self>, BaselineError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path).map_err(|err| BaselineError::Io {
+            path: path.to_path_buf(),
+            err,
+        })?;
+
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|err| BaselineError::Parse {
+                path: path.to_path_buf(),
+                err,
+            })
+    }
+
+    /// Save the baseline to the given path, creating the parent directories if needed.
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let content = serde_json::to_string(self).expect("Failed to serialize baseline");
+        let write = || {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+
+            fs::write(path, content)
+        };
+
+        write().map_err(|err| BaselineError::Io {
+            path: path.to_path_buf(),
+            err,
+        })
+    }
+
+    /// The key of a day and its input in the baseline.
+    ///
+    /// The timings on different inputs aren't comparable, e.g. an example and the real input.
+    pub fn day_key(year: u16, day: u8, input_path: &str) -> String {
+        format!("{}:{input_path}", Self::legacy_day_key(year, day))
+    }
+
+    /// The key of a day in baselines that were saved before the input was part of the key.
+    fn legacy_day_key(year: u16, day: u8) -> String {
+        format!("{year}/day_{day:0>2}")
+    }
+
+    /// The results of a day on the given input, if they were saved.
+    pub fn day(&self, year: u16, day: u8, input_path: &str) -> Option<&DayBaseline> {
+        self.days
+            .get(&Self::day_key(year, day, input_path))
+            .or_else(|| {
+                self.days
+                    .get(&Self::legacy_day_key(year, day))
+                    .filter(|legacy| legacy.input_path == input_path)
+            })
+    }
+
+    /// Save the results of a day, replacing the previous results on the same input only.
+    pub fn insert_day(&mut self, year: u16, day: u8, results: DayBaseline) {
+        let legacy_key = Self::legacy_day_key(year, day);
+        let replaces_legacy = self
+            .days
+            .get(&legacy_key)
+            .is_some_and(|legacy| legacy.input_path == results.input_path);
+        if replaces_legacy {
+            self.days.remove(&legacy_key);
+        }
+
+        let key = Self::day_key(year, day, &results.input_path);
+        self.days.insert(key, results);
+    }
+}
+
+impl DayBaseline {
+    /// Empty results for the input, recorded now on this machine.
+    pub fn new(input_path: String) -> Self {
+        Self {
+            input_path,
+            machine: Some(MachineInfo::current()),
+            git_revision: git_revision(),
+            updated_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the results were recorded on another machine, so the timings may not be comparable.
+    pub fn is_from_other_machine(&self) -> bool {
+        self.machine
+            .as_ref()
+            .is_some_and(|machine| *machine != MachineInfo::current())
+    }
+
+    /// The stored samples of a part of the puzzle.
+    pub fn times(&self, part: u8) -> Vec<Duration> {
+        let times = match part {
+            1 => &self.part_1_times_ns,
+            2 => &self.part_2_times_ns,
+            _ => panic!("Invalid puzzle part {part}"),
+        };
+
+        times.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    /// Store the samples of a part of the puzzle.
+    pub fn set_times(&mut self, part: u8, times: &[Duration]) {
+        let times_ns = times
+            .iter()
+            .map(|time| time.as_nanos().try_into().unwrap_or(u64::MAX))
+            .collect();
+
+        match part {
+            1 => self.part_1_times_ns = times_ns,
+            2 => self.part_2_times_ns = times_ns,
+            _ => panic!("Invalid puzzle part {part}"),
+        }
+    }
+}
+
+/// The current git revision, with a `-dirty` suffix if there are uncommitted changes.
+fn git_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let revision = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty());
+
+    Some(if dirty {
+        format!("{revision}-dirty")
+    } else {
+        revision
+    })
+}
+
+/// How the timing of a part compares to the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BaselineComparison {
+    /// The change of the median time, in percent.
+    pub change_percent: f64,
+    /// The probability to see a difference like this if the timings were the same,
+    /// according to the Mann-Whitney U test.
+    pub p_value: f64,
+    pub significant: bool,
+}
+
+impl BaselineComparison {
+    /// Compare the samples of a run against the samples of the baseline.
+    pub fn new(baseline: &[Duration], times: &[Duration]) -> Option<Self> {
+        let baseline_median = BenchStats::new(baseline)?.median.as_secs_f64();
+        let median = BenchStats::new(times)?.median.as_secs_f64();
+        let p_value = mann_whitney_p_value(baseline, times);
+
+        Some(Self {
+            change_percent: (median - baseline_median) / baseline_median * 100.0,
+            p_value,
+            significant: p_value < SIGNIFICANCE_LEVEL,
+        })
+    }
+}
+
+impl Display for BaselineComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verdict = match (self.significant, self.change_percent < 0.0) {
+            (false, _) => "no significant change",
+            (true, true) => "faster",
+            (true, false) => "slower",
+        };
+
+        write!(
+            f,
+            "{:+.2}% vs baseline, {verdict}, p = {:.3}",
+            self.change_percent, self.p_value
+        )
+    }
+}
+
+/// The two-sided p-value of the Mann-Whitney U test, using the normal approximation.
+///
+/// Unlike a t-test, this doesn't assume that the timings are normally distributed,
+/// which they rarely are because of outliers.
+fn mann_whitney_p_value(a: &[Duration], b: &[Duration]) -> f64 {
+    let mut samples: Vec<(Duration, bool)> = a
+        .iter()
+        .map(|&time| (time, true))
+        .chain(b.iter().map(|&time| (time, false)))
+        .collect();
+    samples.sort_unstable();
+
+    // Assign ranks starting at 1, tied samples share the average of their ranks
+    let mut rank_sum_a = 0.0;
+    let mut start = 0;
+    while start < samples.len() {
+        let end = start
+            + samples[start..]
+                .iter()
+                .take_while(|(time, _)| *time == samples[start].0)
+                .count();
+        let rank = (start + end + 1) as f64 / 2.0;
+        rank_sum_a += rank * samples[start..end].iter().filter(|(_, in_a)| *in_a).count() as f64;
+        start = end;
+    }
+
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let u = rank_sum_a - n_a * (n_a + 1.0) / 2.0;
+    let mean = n_a * n_b / 2.0;
+    let std_dev = (n_a * n_b * (n_a + n_b + 1.0) / 12.0).sqrt();
+
+    if std_dev == 0.0 {
+        return 1.0;
+    }
+
+    let z = (u - mean).abs() / std_dev;
+    (1.0 - erf(z / 2f64.sqrt())).clamp(0.0, 1.0)
+}
+
+/// The error function, approximated as in Abramowitz and Stegun 7.1.26.
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+    let polynomial = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+
+    (1.0 - polynomial * (-x * x).exp()).copysign(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: impl IntoIterator<Item = u64>) -> Vec<Duration> {
+        values.into_iter().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_compare_with_baseline() {
+        let baseline = millis((0..50).map(|i| 98 + i % 5));
+
+        let same =
+            BaselineComparison::new(&baseline, &millis((0..50).map(|i| 98 + (i + 2) % 5))).unwrap();
+        assert_eq!(same.change_percent, 0.0);
+        assert!(!same.significant);
+
+        let faster =
+            BaselineComparison::new(&baseline, &millis((0..50).map(|i| 88 + i % 5))).unwrap();
+        assert!((faster.change_percent + 10.0).abs() < 1e-9);
+        assert!(faster.significant);
+        assert!(faster.p_value < 1e-6);
+
+        assert_eq!(BaselineComparison::new(&[], &baseline), None);
+    }
+
+    #[test]
+    fn test_erf() {
+        assert!(erf(0.0).abs() < 1e-6);
+        assert!((erf(1.0) - 0.842_700_79).abs() < 1e-6);
+        assert!((erf(-1.0) + 0.842_700_79).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_day_baseline_json() {
+        let mut day = DayBaseline {
+            input_path: "inputs/2023/day_05.txt".to_string(),
+            ..Default::default()
+        };
+        day.set_times(2, &millis([1, 2]));
+
+        assert_eq!(
+            serde_json::to_string(&day).unwrap(),
+            r#"{"input_path":"inputs/2023/day_05.txt","machine":null,"git_revision":null,"updated_at":0,"part_2_times_ns":[1000000,2000000]}"#
+        );
+        assert_eq!(day.times(2), millis([1, 2]));
+        assert_eq!(day.times(1), vec![]);
+    }
+
+    #[test]
+    fn test_baseline_per_input() {
+        let day = |input_path: &str, time| {
+            let mut day = DayBaseline {
+                input_path: input_path.to_string(),
+                ..Default::default()
+            };
+            day.set_times(1, &millis([time]));
+            day
+        };
+
+        let mut baseline = Baseline::default();
+        baseline.insert_day(2023, 5, day("inputs/2023/day_05.txt", 100));
+        baseline.insert_day(2023, 5, day("inputs/2023/day_05.example_1.txt", 1));
+
+        let input = baseline.day(2023, 5, "inputs/2023/day_05.txt").unwrap();
+        assert_eq!(input.times(1), millis([100]));
+        let example = baseline.day(2023, 5, "inputs/2023/day_05.example_1.txt");
+        assert_eq!(example.unwrap().times(1), millis([1]));
+        assert_eq!(baseline.day(2023, 5, "other.txt"), None);
+        assert_eq!(baseline.day(2023, 6, "inputs/2023/day_05.txt"), None);
+
+        // A baseline saved before the input was part of the key is only used for the same input
+        let mut legacy = Baseline::default();
+        let legacy_key = "2023/day_05".to_string();
+        legacy
+            .days
+            .insert(legacy_key.clone(), day("inputs/2023/day_05.txt", 100));
+        assert!(legacy.day(2023, 5, "inputs/2023/day_05.txt").is_some());
+        assert_eq!(legacy.day(2023, 5, "other.txt"), None);
+
+        legacy.insert_day(2023, 5, day("other.txt", 1));
+        assert!(legacy.days.contains_key(&legacy_key));
+        legacy.insert_day(2023, 5, day("inputs/2023/day_05.txt", 90));
+        assert!(!legacy.days.contains_key(&legacy_key));
+        let input = legacy.day(2023, 5, "inputs/2023/day_05.txt").unwrap();
+        assert_eq!(input.times(1), millis([90]));
+    }
+
+    #[test]
+    fn test_day_baseline_machine() {
+        let mut day = DayBaseline::new("inputs/2023/day_05.txt".to_string());
+        assert!(!day.is_from_other_machine());
+
+        day.machine.as_mut().unwrap().cpus += 1;
+        assert!(day.is_from_other_machine());
+
+        // Baselines saved before the machine was recorded per day can't be told apart
+        day.machine = None;
+        assert!(!day.is_from_other_machine());
+    }
+}
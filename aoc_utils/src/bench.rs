@@ -0,0 +1,172 @@
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::answer::AocAnswer;
+use crate::output::serialize_duration;
+
+/// How a part of the puzzle is benchmarked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// How long to run the part before measuring it, e.g. to fill the caches.
+    pub warmup: Duration,
+
+    /// How long to measure the part.
+    pub time: Duration,
+
+    /// The maximum number of samples to take.
+    pub max_samples: Option<usize>,
+}
+
+/// Benchmark the function, after it has already been run once with the `expected` result.
+///
+/// Returns the measured samples, without the warmup runs.
+pub fn benchmark<T: AocAnswer>(
+    options: &BenchOptions,
+    expected: &T,
+    mut run: impl FnMut() -> T,
+) -> Vec<Duration> {
+    // The deadlines are fixed up front, so a slow warmup shortens the measurements
+    let warmup_end = Instant::now() + options.warmup;
+    let end = warmup_end + options.time;
+
+    while Instant::now() < warmup_end {
+        assert_eq!(*expected, run());
+    }
+
+    let mut times = Vec::new();
+
+    // Take at least one sample, even if the part takes longer than the time budget
+    while times.is_empty()
+        || (Instant::now() < end && options.max_samples.is_none_or(|max| times.len() < max))
+    {
+        let iter_start = Instant::now();
+        let res = run();
+        times.push(iter_start.elapsed());
+        assert_eq!(*expected, res);
+    }
+
+    times
+}
+
+/// Statistics about the samples of a benchmark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct BenchStats {
+    pub samples: usize,
+    #[serde(rename = "mean_ns", serialize_with = "serialize_duration")]
+    pub mean: Duration,
+    #[serde(rename = "median_ns", serialize_with = "serialize_duration")]
+    pub median: Duration,
+    #[serde(rename = "p5_ns", serialize_with = "serialize_duration")]
+    pub p5: Duration,
+    #[serde(rename = "p95_ns", serialize_with = "serialize_duration")]
+    pub p95: Duration,
+    #[serde(rename = "std_dev_ns", serialize_with = "serialize_duration")]
+    pub std_dev: Duration,
+    #[serde(rename = "min_ns", serialize_with = "serialize_duration")]
+    pub min: Duration,
+    #[serde(rename = "max_ns", serialize_with = "serialize_duration")]
+    pub max: Duration,
+    /// The number of samples outside of the Tukey fences (1.5 times the interquartile range).
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Calculate the statistics of the samples, if there are any.
+    pub fn new(times: &[Duration]) -> Option<Self> {
+        if times.is_empty() {
+            return None;
+        }
+
+        let mut sorted = times.to_vec();
+        sorted.sort_unstable();
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = if secs.len() > 1 {
+            secs.iter().map(|time| (time - mean).powi(2)).sum::<f64>() / (secs.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&secs, 0.25);
+        let q3 = percentile(&secs, 0.75);
+        let iqr = q3 - q1;
+        let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        let outliers = secs
+            .iter()
+            .filter(|&&time| time < lower_fence || time > upper_fence)
+            .count();
+
+        Some(Self {
+            samples: sorted.len(),
+            mean: Duration::from_secs_f64(mean),
+            median: Duration::from_secs_f64(percentile(&secs, 0.5)),
+            p5: Duration::from_secs_f64(percentile(&secs, 0.05)),
+            p95: Duration::from_secs_f64(percentile(&secs, 0.95)),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            outliers,
+        })
+    }
+}
+
+/// The percentile of the sorted values, interpolating linearly between the closest ranks.
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    let rank = fraction * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_stats() {
+        let mut times: Vec<_> = (1..=99).map(Duration::from_millis).collect();
+        times.push(Duration::from_millis(1000));
+
+        let stats = BenchStats::new(&times).unwrap();
+
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.median, Duration::from_micros(50_500));
+        assert_eq!(stats.p5, Duration::from_micros(5_950));
+        assert_eq!(stats.p95, Duration::from_micros(95_050));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(1000));
+        assert_eq!(stats.mean, Duration::from_micros(59_500));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn test_bench_stats_single_sample() {
+        let stats = BenchStats::new(&[Duration::from_millis(3)]).unwrap();
+
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(BenchStats::new(&[]), None);
+    }
+
+    #[test]
+    fn test_benchmark_max_samples() {
+        let options = BenchOptions {
+            warmup: Duration::ZERO,
+            time: Duration::from_secs(60),
+            max_samples: Some(10),
+        };
+
+        let mut runs = 0;
+        let times = benchmark(&options, &42, || {
+            runs += 1;
+            42
+        });
+
+        assert_eq!(times.len(), 10);
+        assert_eq!(runs, 10);
+    }
+}
@@ -0,0 +1,62 @@
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// The current time and a way to wait, which can be replaced in tests to avoid real waiting.
+pub trait Clock {
+    /// The current time.
+    fn now(&self) -> SystemTime;
+
+    /// Block the current thread for the given duration.
+    fn sleep(&self, duration: Duration);
+}
+
+/// The clock of the operating system.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// A clock for tests, where sleeping advances the time immediately.
+#[cfg(test)]
+pub(crate) mod fake {
+    use std::cell::{Cell, RefCell};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use super::Clock;
+
+    #[derive(Debug)]
+    pub struct FakeClock {
+        now: Cell<SystemTime>,
+        /// The durations of all calls to [`Clock::sleep`].
+        pub sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl FakeClock {
+        /// A clock showing the given number of seconds since the Unix epoch.
+        pub fn at(secs: u64) -> Self {
+            Self {
+                now: Cell::new(UNIX_EPOCH + Duration::from_secs(secs)),
+                sleeps: RefCell::default(),
+            }
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> SystemTime {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+            self.sleeps.borrow_mut().push(duration);
+        }
+    }
+}
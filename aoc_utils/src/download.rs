@@ -0,0 +1,304 @@
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::http::{HttpClient, HttpResponse, NetworkError};
+use crate::metadata::InputMetadata;
+use crate::unlock::format_countdown;
+
+/// The reasons why the input could not be downloaded.
+#[derive(Debug)]
+pub enum DownloadError {
+    /// The request could not be sent or the reply could not be received.
+    Network(NetworkError),
+
+    /// The session cookie is missing, invalid or has expired.
+    SessionExpired,
+
+    /// The server rejected the request (status 400).
+    BadRequest(String),
+
+    /// The puzzle does not exist or has not been unlocked yet (status 404).
+    NotFound,
+
+    /// The puzzle unlocks later, so the input was not requested.
+    NotUnlocked { left: Duration },
+
+    /// The server failed to handle the request (status 500).
+    ServerError(String),
+
+    /// The server replied with another unexpected status.
+    UnexpectedStatus(u16),
+
+    /// The server replied with an empty input.
+    EmptyInput,
+
+    /// The input could not be saved to the file system.
+    Write { path: PathBuf, err: io::Error },
+}
+
+impl Display for DownloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Network(err) => write!(f, "Failed to reach the server: {err}"),
+            Self::SessionExpired => write!(
+                f,
+                "The session cookie is invalid or has expired, please provide a new one"
+            ),
+            Self::BadRequest(body) => write!(f, "The server rejected the request: {body}"),
+            Self::NotFound => write!(f, "The puzzle does not exist or has not been unlocked yet"),
+            Self::NotUnlocked { left } => write!(
+                f,
+                "The puzzle unlocks in {}, use `aoc wait` to download the input as soon as it does",
+                format_countdown(*left)
+            ),
+            Self::ServerError(body) => write!(
+                f,
+                "The server failed to handle the request, is the session cookie valid? {body}"
+            ),
+            Self::UnexpectedStatus(status) => {
+                write!(f, "The server replied with unexpected status {status}")
+            }
+            Self::EmptyInput => write!(f, "The server replied with an empty input"),
+            Self::Write { path, err } => {
+                write!(f, "Failed to save the input to {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DownloadError {}
+
+/// The text that the server replies with when the request is not authenticated.
+const LOG_IN_MESSAGE: &str = "Please log in to get your puzzle input";
+
+/// Download the input of a puzzle.
+///
+/// Only returns the body of the reply if it really is the puzzle input.
+pub fn download_input(
+    client: &dyn HttpClient,
+    base_url: &str,
+    year: u16,
+    day: u8,
+    session_cookie: &str,
+) -> Result<String, DownloadError> {
+    let response = client
+        .get(
+            &format!("{base_url}/{year}/day/{day}/input"),
+            session_cookie,
+        )
+        .map_err(DownloadError::Network)?;
+
+    match response.status {
+        200 if response.body.contains(LOG_IN_MESSAGE) => Err(DownloadError::SessionExpired),
+        200 if response.body.trim().is_empty() => Err(DownloadError::EmptyInput),
+        400 if response.body.contains(LOG_IN_MESSAGE) => Err(DownloadError::SessionExpired),
+        _ => check_status(response),
+    }
+}
+
+/// Check that the server accepts the session cookie, by requesting a page that requires logging in.
+///
+/// The endpoint is usually the input of a puzzle, e.g. `https://adventofcode.com/2023/day/1/input`.
+pub fn check_session_cookie(
+    client: &dyn HttpClient,
+    endpoint: &str,
+    session_cookie: &str,
+) -> Result<(), DownloadError> {
+    let response = client
+        .get(endpoint, session_cookie)
+        .map_err(DownloadError::Network)?;
+
+    match response.status {
+        _ if response.body.contains(LOG_IN_MESSAGE) => Err(DownloadError::SessionExpired),
+        401 | 403 => Err(DownloadError::SessionExpired),
+        _ => check_status(response).map(|_| ()),
+    }
+}
+
+/// Download the description page of a puzzle.
+///
+/// Without a session cookie, the page only contains the first part of the puzzle.
+pub fn download_puzzle_page(
+    client: &dyn HttpClient,
+    base_url: &str,
+    year: u16,
+    day: u8,
+    session_cookie: Option<&str>,
+) -> Result<String, DownloadError> {
+    let response = client
+        .get(
+            &format!("{base_url}/{year}/day/{day}"),
+            session_cookie.unwrap_or_default(),
+        )
+        .map_err(DownloadError::Network)?;
+
+    check_status(response)
+}
+
+fn check_status(response: HttpResponse) -> Result<String, DownloadError> {
+    match response.status {
+        200 => Ok(response.body),
+        400 => Err(DownloadError::BadRequest(response.body.trim().to_string())),
+        404 => Err(DownloadError::NotFound),
+        500 => Err(DownloadError::ServerError(response.body.trim().to_string())),
+        status => Err(DownloadError::UnexpectedStatus(status)),
+    }
+}
+
+/// Download the input of a puzzle and save it, together with its [`InputMetadata`].
+pub fn download_and_save_input(
+    client: &dyn HttpClient,
+    base_url: &str,
+    year: u16,
+    day: u8,
+    session_cookie: &str,
+    file_path: &Path,
+) -> Result<String, DownloadError> {
+    let text = download_input(client, base_url, year, day, session_cookie)?;
+    save_input(file_path, &text)?;
+
+    // The metadata only helps to detect edited inputs, so the input is usable without it
+    let source = format!("{base_url}/{year}/day/{day}/input");
+    let metadata_path = InputMetadata::path_for_input(file_path);
+    if let Err(err) = InputMetadata::new(&source, &text).save(&metadata_path) {
+        eprintln!("Warning: {err}");
+    }
+
+    Ok(text)
+}
+
+/// Save the input to the file system, creating the parent directories if needed.
+pub fn save_input(file_path: &Path, input: &str) -> Result<(), DownloadError> {
+    let write = || {
+        if let Some(dir) = file_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(file_path, input)
+    };
+
+    write().map_err(|err| DownloadError::Write {
+        path: file_path.to_path_buf(),
+        err,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::serve_once;
+    use crate::http::ReqwestClient;
+
+    /// A client that replies to every request with the same response.
+    struct FakeClient(HttpResponse);
+
+    impl HttpClient for FakeClient {
+        fn get(&self, _url: &str, _session_cookie: &str) -> Result<HttpResponse, NetworkError> {
+            Ok(self.0.clone())
+        }
+
+        fn post_form(
+            &self,
+            _url: &str,
+            _session_cookie: &str,
+            _form: &[(&str, &str)],
+        ) -> Result<HttpResponse, NetworkError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn download(status: u16, body: &str) -> Result<String, DownloadError> {
+        download_input(
+            &FakeClient(HttpResponse::new(status, body)),
+            "https://adventofcode.com",
+            2023,
+            5,
+            "secret",
+        )
+    }
+
+    #[test]
+    fn test_download_input() {
+        assert_eq!(download(200, "seeds: 79 14\n").unwrap(), "seeds: 79 14\n");
+        assert!(matches!(
+            download(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            ),
+            Err(DownloadError::SessionExpired)
+        ));
+        assert!(matches!(
+            download(400, "Bad request\n"),
+            Err(DownloadError::BadRequest(_))
+        ));
+        assert!(matches!(
+            download(404, "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"),
+            Err(DownloadError::NotFound)
+        ));
+        assert!(matches!(
+            download(500, "Internal Server Error\n"),
+            Err(DownloadError::ServerError(_))
+        ));
+        assert!(matches!(
+            download(503, ""),
+            Err(DownloadError::UnexpectedStatus(503))
+        ));
+        assert!(matches!(
+            download(200, "\n"),
+            Err(DownloadError::EmptyInput)
+        ));
+    }
+
+    #[test]
+    fn test_download_input_from_server() {
+        let (base_url, server) = serve_once(200, "0 3 6 9 12 15\n");
+
+        let input = download_input(&ReqwestClient::new(), &base_url, 2023, 9, "secret").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "0 3 6 9 12 15\n");
+        assert_eq!(request.request_line, "GET /2023/day/9/input HTTP/1.1");
+        assert_eq!(request.cookie, "session=secret");
+    }
+
+    #[test]
+    fn test_download_puzzle_page() {
+        let (base_url, server) = serve_once(200, "<main><article></article></main>");
+
+        let page = download_puzzle_page(&ReqwestClient::new(), &base_url, 2023, 9, None).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(page, "<main><article></article></main>");
+        assert_eq!(request.request_line, "GET /2023/day/9 HTTP/1.1");
+        // Without a session cookie, no empty cookie is sent
+        assert_eq!(request.cookie, "");
+    }
+
+    #[test]
+    fn test_check_session_cookie() {
+        let check = |status, body| {
+            check_session_cookie(
+                &FakeClient(HttpResponse::new(status, body)),
+                "https://adventofcode.com/2023/day/1/input",
+                "secret",
+            )
+        };
+
+        assert!(check(200, "1abc2\n").is_ok());
+        assert!(matches!(
+            check(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            ),
+            Err(DownloadError::SessionExpired)
+        ));
+        assert!(matches!(check(403, ""), Err(DownloadError::SessionExpired)));
+        assert!(matches!(
+            check(500, "Internal Server Error\n"),
+            Err(DownloadError::ServerError(_))
+        ));
+    }
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+/// Parse a duration from the command line, e.g. `5s`, `500ms`, `2m` or `1.5` (seconds).
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let unit_start = value
+        .find(|ch: char| !ch.is_ascii_digit() && ch != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(unit_start);
+
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("Invalid duration {value:?}"))?;
+
+    let seconds = match unit.trim() {
+        "" | "s" => number,
+        "ms" => number / 1_000.0,
+        "us" | "µs" => number / 1_000_000.0,
+        "m" | "min" => number * 60.0,
+        "h" => number * 60.0 * 60.0,
+        unit => {
+            return Err(format!(
+                "Invalid duration unit {unit:?}, use h, m, s, ms or us"
+            ))
+        }
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|err| format!("Invalid duration {value:?}: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("5s"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+        assert!(parse_duration("5 parsecs").is_err());
+        assert!(parse_duration("s").is_err());
+    }
+}
@@ -0,0 +1,73 @@
+/// Declare tests for the examples of the puzzle description.
+///
+/// Each example becomes a module with a `#[test]` function per part, which normalizes and parses
+/// the input like [`AocDay::INPUT_NORMALIZATION`](crate::AocDay::INPUT_NORMALIZATION) says
+/// and compares the answer of the part with the expected answer.
+/// The input can be any `&str` expression, e.g. a literal, a constant or `include_str!("...")`.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     aoc_utils::examples!(Day14::<10> {
+///         example: "O....#....\nO.OO#....#" => { part_1: 136, part_2: 64 },
+///         from_file: include_str!("../examples/day_14.txt") => { part_2: 64 },
+///     });
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($day:ty {
+        $( $name:ident: $input:expr => { $( $part:ident: $expected:expr ),+ $(,)? } ),+ $(,)?
+    }) => {
+        $(
+            mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                $(
+                    #[test]
+                    fn $part() {
+                        let input = <$day as $crate::AocDay<_, _>>::INPUT_NORMALIZATION.apply($input);
+                        let parsed = <$day as $crate::AocDay<_, _>>::parse(input);
+
+                        assert_eq!(<$day as $crate::AocDay<_, _>>::$part(&parsed), $expected);
+                    }
+                )+
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::AocDay;
+
+    struct Sum<const FACTOR: u32>;
+
+    impl<const FACTOR: u32> AocDay<u32, u32> for Sum<FACTOR> {
+        const DAY: u8 = 1;
+
+        type Parsed<'a> = Vec<u32>;
+
+        fn parse(input: &str) -> Vec<u32> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part_1(input: &Vec<u32>) -> u32 {
+            input.iter().sum()
+        }
+
+        fn part_2(input: &Vec<u32>) -> u32 {
+            input.iter().map(|num| num * FACTOR).sum()
+        }
+    }
+
+    const INPUT: &str = "1\n2\n3";
+
+    examples!(Sum::<2> {
+        inline: "4\n5" => { part_1: 9, part_2: 18 },
+        constant: INPUT => { part_2: 12 },
+    });
+}
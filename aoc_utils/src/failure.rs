@@ -0,0 +1,147 @@
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::Once;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::output::serialize_duration;
+
+/// Why a part of the puzzle did not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "failure", rename_all = "snake_case")]
+pub enum PartFailure {
+    /// The part still calls `todo!()` or `unimplemented!()`.
+    NotImplemented,
+
+    /// The part panicked, e.g. because of an `unwrap` on unexpected input.
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+
+    /// The part did not finish within the timeout.
+    TimedOut {
+        #[serde(rename = "after_ns", serialize_with = "serialize_duration")]
+        after: Duration,
+    },
+}
+
+impl Display for PartFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotImplemented => write!(f, "not implemented"),
+            Self::Panicked {
+                message,
+                location: Some(location),
+            } => write!(f, "panicked at {location}: {message}"),
+            Self::Panicked {
+                message,
+                location: None,
+            } => write!(f, "panicked: {message}"),
+            Self::TimedOut { after } => write!(f, "timed out after {after:?}"),
+        }
+    }
+}
+
+impl PartFailure {
+    fn from_panic(message: String, location: Option<String>) -> Self {
+        // The messages of `todo!()` and `unimplemented!()`, optionally followed by a custom message
+        let not_implemented = ["not yet implemented", "not implemented"]
+            .iter()
+            .any(|prefix| {
+                message
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with(": "))
+            });
+
+        if not_implemented {
+            Self::NotImplemented
+        } else {
+            Self::Panicked { message, location }
+        }
+    }
+}
+
+thread_local! {
+    /// Whether panics on this thread are caught by [`catch_failure`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+
+    /// The message and location of the last caught panic on this thread.
+    static LAST_PANIC: RefCell<Option<(String, Option<String>)>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Run the function, turning a panic into a [`PartFailure`].
+///
+/// The panic is not printed by the default hook, since it is part of the report instead.
+pub fn catch_failure<T>(run: impl FnOnce() -> T) -> Result<T, PartFailure> {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                LAST_PANIC.set(Some((
+                    panic_message(info),
+                    info.location().map(ToString::to_string),
+                )));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(run));
+    CATCHING.set(was_catching);
+
+    result.map_err(|_| {
+        let (message, location) = LAST_PANIC
+            .take()
+            .unwrap_or_else(|| ("unknown panic".to_string(), None));
+
+        PartFailure::from_panic(message, location)
+    })
+}
+
+fn panic_message(info: &PanicHookInfo) -> String {
+    if let Some(message) = info.payload().downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = info.payload().downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_failure() {
+        assert_eq!(catch_failure(|| 42), Ok(42));
+        assert_eq!(
+            catch_failure(|| -> u32 { todo!() }),
+            Err(PartFailure::NotImplemented)
+        );
+        assert_eq!(
+            catch_failure(|| -> u32 { unimplemented!("part 2") }),
+            Err(PartFailure::NotImplemented)
+        );
+
+        let Err(PartFailure::Panicked { message, location }) =
+            catch_failure(|| "abc".parse::<u32>().unwrap())
+        else {
+            panic!("Expected a panic");
+        };
+
+        assert_eq!(
+            message,
+            "called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }"
+        );
+        assert!(location.unwrap().starts_with("aoc_utils/src/failure.rs:"));
+    }
+}
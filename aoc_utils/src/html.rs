@@ -0,0 +1,146 @@
+/// Extract the example inputs from a puzzle description.
+///
+/// The examples are the `<pre><code>` blocks introduced by a paragraph mentioning an example,
+/// e.g. "For example:". Other blocks, such as the state of a grid after some steps, are skipped.
+/// Markup inside of the blocks (e.g. `<em>` for highlighting) is removed.
+pub fn extract_examples(html: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<pre><code>") {
+        let before = &rest[..start];
+        rest = &rest[start + "<pre><code>".len()..];
+
+        let Some(end) = rest.find("</code></pre>") else {
+            break;
+        };
+
+        if introduces_example(before) {
+            examples.push(decode_entities(&strip_tags(&rest[..end])));
+        }
+        rest = &rest[end..];
+    }
+
+    examples
+}
+
+/// Whether the last paragraph of the HTML announces an example.
+fn introduces_example(html: &str) -> bool {
+    html.rfind("<p>").is_some_and(|start| {
+        strip_tags(&html[start..])
+            .to_lowercase()
+            .contains("example")
+    })
+}
+
+/// Remove all HTML tags from the text.
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/// Replace the HTML entities used in the puzzle descriptions by the characters they stand for.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+
+        if let Some((ch, end)) = entity {
+            decoded.push(ch);
+            rest = &rest[end + 1..];
+        } else {
+            decoded.push('&');
+            rest = &rest[1..];
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = if let Some(hex) = entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+            {
+                u32::from_str_radix(hex, 16).ok()?
+            } else {
+                entity.strip_prefix('#')?.parse().ok()?
+            };
+
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_examples() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 8: Haunted Wasteland ---</h2><p>For example:</p>
+<pre><code>RL
+
+AAA = (BBB, CCC)
+ZZZ = (ZZZ, ZZZ)
+</code></pre>
+<p>Starting at <code>AAA</code>, follow the instructions:</p>
+<pre><code>AAA -&gt; CCC -&gt; ZZZ
+</code></pre>
+<p>Here's another example:</p>
+<pre><code><em>LLR</em>
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+</code></pre>
+</article>
+</main>"#;
+
+        assert_eq!(
+            extract_examples(html),
+            vec![
+                "RL\n\nAAA = (BBB, CCC)\nZZZ = (ZZZ, ZZZ)\n",
+                "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\n"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;= b &amp;&amp; c &gt; d"),
+            "a <= b && c > d"
+        );
+        assert_eq!(decode_entities("&#39;x&#x27; &quot;"), "'x' \"");
+        assert_eq!(decode_entities("R&D & more;"), "R&D & more;");
+    }
+}
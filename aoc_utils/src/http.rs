@@ -0,0 +1,268 @@
+use std::env;
+use std::error::Error;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use crate::clock::{Clock, SystemClock};
+
+/// The error returned when a request could not be sent or the reply could not be received.
+pub type NetworkError = Box<dyn Error + Send + Sync>;
+
+/// The reply of the server to a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+        }
+    }
+}
+
+/// The requests that are sent to the Advent of Code server.
+///
+/// This allows to replace the server by a fake one in tests.
+pub trait HttpClient {
+    /// Send a GET request, authenticated with the session cookie.
+    fn get(&self, url: &str, session_cookie: &str) -> Result<HttpResponse, NetworkError>;
+
+    /// Send a POST request with a form body, authenticated with the session cookie.
+    fn post_form(
+        &self,
+        url: &str,
+        session_cookie: &str,
+        form: &[(&str, &str)],
+    ) -> Result<HttpResponse, NetworkError>;
+}
+
+/// The environment variable with contact details to add to the User-Agent, e.g. an email address.
+pub const CONTACT_ENV_VAR: &str = "AOC_CONTACT";
+
+/// The minimum time between two requests to the server, so that it isn't flooded with requests.
+pub const MIN_REQUEST_GAP: Duration = Duration::from_secs(1);
+
+/// The User-Agent of the requests, so that the operators of the server know where they come from.
+pub fn user_agent() -> String {
+    let user_agent = concat!(
+        env!("CARGO_PKG_NAME"),
+        "/",
+        env!("CARGO_PKG_VERSION"),
+        " (Advent of Code solutions runner in Rust)"
+    );
+
+    match env::var(CONTACT_ENV_VAR) {
+        Ok(contact) if !contact.trim().is_empty() => {
+            format!("{user_agent} contact: {}", contact.trim())
+        }
+        _ => user_agent.to_string(),
+    }
+}
+
+/// Spaces out the requests, so that there is a minimum gap between them.
+#[derive(Debug)]
+pub struct RateLimiter {
+    min_gap: Duration,
+    last_request: Mutex<Option<SystemTime>>,
+}
+
+impl RateLimiter {
+    pub const fn new(min_gap: Duration) -> Self {
+        Self {
+            min_gap,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Wait until the next request may be sent, and remember when it is sent.
+    pub fn wait(&self, clock: &dyn Clock) {
+        let mut last_request = self.last_request.lock().unwrap();
+
+        if let Some(last_request) = *last_request {
+            let elapsed = clock.now().duration_since(last_request).unwrap_or_default();
+
+            if elapsed < self.min_gap {
+                clock.sleep(self.min_gap - elapsed);
+            }
+        }
+
+        *last_request = Some(clock.now());
+    }
+}
+
+/// All requests of the process share one rate limit.
+static RATE_LIMITER: RateLimiter = RateLimiter::new(MIN_REQUEST_GAP);
+
+/// The HTTP client used to talk to the real server.
+#[derive(Debug, Clone)]
+pub struct ReqwestClient {
+    client: reqwest::blocking::Client,
+}
+
+impl Default for ReqwestClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReqwestClient {
+    pub fn new() -> Self {
+        let client = reqwest::blocking::Client::builder()
+            .user_agent(user_agent())
+            .build()
+            .expect("Failed to create the HTTP client");
+
+        Self { client }
+    }
+
+    fn send(request: reqwest::blocking::RequestBuilder) -> Result<HttpResponse, NetworkError> {
+        RATE_LIMITER.wait(&SystemClock);
+
+        let response = request.send()?;
+        let status = response.status().as_u16();
+        let body = response.text()?;
+
+        Ok(HttpResponse { status, body })
+    }
+}
+
+impl HttpClient for ReqwestClient {
+    fn get(&self, url: &str, session_cookie: &str) -> Result<HttpResponse, NetworkError> {
+        Self::send(with_cookie(self.client.get(url), session_cookie))
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session_cookie: &str,
+        form: &[(&str, &str)],
+    ) -> Result<HttpResponse, NetworkError> {
+        Self::send(with_cookie(self.client.post(url), session_cookie).form(form))
+    }
+}
+
+/// Authenticate the request with the session cookie, unless there is none.
+fn with_cookie(
+    request: reqwest::blocking::RequestBuilder,
+    session_cookie: &str,
+) -> reqwest::blocking::RequestBuilder {
+    if session_cookie.is_empty() {
+        request
+    } else {
+        request.header("Cookie", format!("session={session_cookie}"))
+    }
+}
+
+/// A local HTTP server for tests, which answers a single request.
+#[cfg(test)]
+pub(crate) mod test_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// The request received by the test server.
+    #[derive(Debug, Default)]
+    pub struct ReceivedRequest {
+        pub request_line: String,
+        pub cookie: String,
+        pub user_agent: String,
+        pub body: String,
+    }
+
+    /// Start a server that replies to one request with the given status and body.
+    ///
+    /// Returns the base URL of the server and a handle to get the received request.
+    pub fn serve_once(status: u16, body: &str) -> (String, JoinHandle<ReceivedRequest>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = ReceivedRequest::default();
+
+            reader.read_line(&mut request.request_line).unwrap();
+            request.request_line = request.request_line.trim_end().to_string();
+
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+
+                if line.is_empty() {
+                    break;
+                }
+
+                let (name, value) = line.split_once(": ").unwrap();
+                match name.to_lowercase().as_str() {
+                    "cookie" => request.cookie = value.to_string(),
+                    "user-agent" => request.user_agent = value.to_string(),
+                    "content-length" => content_length = value.parse().unwrap(),
+                    _ => {}
+                }
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.body = String::from_utf8(request_body).unwrap();
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::fake::FakeClock;
+    use test_server::serve_once;
+
+    #[test]
+    fn test_rate_limiter() {
+        let clock = FakeClock::at(1000);
+        let limiter = RateLimiter::new(Duration::from_secs(2));
+
+        limiter.wait(&clock);
+        clock.sleep(Duration::from_millis(500));
+        limiter.wait(&clock);
+        clock.sleep(Duration::from_secs(3));
+        limiter.wait(&clock);
+
+        assert_eq!(
+            *clock.sleeps.borrow(),
+            [
+                Duration::from_millis(500),
+                Duration::from_millis(1500),
+                Duration::from_secs(3)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_user_agent() {
+        let (base_url, server) = serve_once(200, "");
+
+        ReqwestClient::new().get(&base_url, "secret").unwrap();
+        let request = server.join().unwrap();
+
+        assert!(request.user_agent.starts_with("aoc_utils/"));
+        assert!(request
+            .user_agent
+            .contains("(Advent of Code solutions runner in Rust)"));
+    }
+}
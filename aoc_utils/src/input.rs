@@ -0,0 +1,260 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use inquire::InquireError;
+
+use crate::profiles::DEFAULT_INPUTS_DIR;
+use crate::{inputs_dir, DownloadError, SessionError, SESSION_ENV_VAR};
+
+/// The reasons why the input for a day could not be obtained.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist and it should not be downloaded.
+    Missing { file_path: String },
+
+    /// The input file does not exist and there is no session cookie to download it without asking.
+    PromptDisabled { file_path: String },
+
+    /// The puzzle description does not contain the requested example.
+    ExampleNotFound {
+        example: usize,
+        count: usize,
+        day: u8,
+    },
+
+    /// The input could not be read.
+    Read { source: String, err: io::Error },
+
+    /// Asking the user for the input failed.
+    Prompt(InquireError),
+
+    /// The session cookie to download the input could not be obtained.
+    Session(SessionError),
+
+    /// Downloading the input failed.
+    Download(DownloadError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { file_path } => {
+                write!(f, "No input provided! You can add it at {file_path}.")
+            }
+            Self::PromptDisabled { file_path } => write!(
+                f,
+                "The input file {file_path} does not exist and prompts are disabled. Add the file, or set the {SESSION_ENV_VAR} environment variable to download it."
+            ),
+            Self::ExampleNotFound {
+                example,
+                count,
+                day,
+            } => write!(
+                f,
+                "Example {example} does not exist, the cached puzzle description contains {count} examples. If it was cached before the second part unlocked, update it with `aoc read {day} --refresh`"
+            ),
+            Self::Read { source, err } => write!(f, "Failed to read the input from {source}: {err}"),
+            Self::Prompt(err) => write!(f, "Failed to ask for the input: {err}"),
+            Self::Session(err) => write!(f, "{err}"),
+            Self::Download(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl From<InquireError> for InputError {
+    fn from(value: InquireError) -> Self {
+        Self::Prompt(value)
+    }
+}
+
+impl From<SessionError> for InputError {
+    fn from(value: SessionError) -> Self {
+        Self::Session(value)
+    }
+}
+
+impl From<DownloadError> for InputError {
+    fn from(value: DownloadError) -> Self {
+        Self::Download(value)
+    }
+}
+
+/// The path to the input file of a day, in the inputs directory of the profile in use.
+pub fn input_file_path(year: u16, day: u8) -> String {
+    format!("{}/{year}/day_{day:0>2}.txt", inputs_dir().display())
+}
+
+/// The path to the cached description page of a puzzle.
+pub fn puzzle_page_path(year: u16, day: u8) -> String {
+    format!("{}/{year}/day_{day:0>2}.html", inputs_dir().display())
+}
+
+/// The input path that stands for reading the input from stdin.
+pub const STDIN_INPUT: &str = "-";
+
+/// The name to show for the source of the input.
+pub fn input_source_name(path: &Path) -> String {
+    if path == Path::new(STDIN_INPUT) {
+        "stdin".to_string()
+    } else {
+        path.display().to_string()
+    }
+}
+
+/// How the input is cleaned up before it is parsed.
+///
+/// The inputs are stored exactly as they were downloaded, usually with a trailing newline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputNormalization {
+    /// Keep the input as it is, including all whitespace.
+    Raw,
+
+    /// Remove the line break at the end of the input, which the server adds to every input.
+    #[default]
+    TrimTrailingNewline,
+
+    /// Remove all whitespace at the start and the end of the input.
+    Trim,
+}
+
+impl InputNormalization {
+    /// Apply the normalization to the input.
+    pub fn apply(self, input: &str) -> &str {
+        match self {
+            Self::Raw => input,
+            Self::TrimTrailingNewline => input
+                .strip_suffix('\n')
+                .map_or(input, |input| input.strip_suffix('\r').unwrap_or(input)),
+            Self::Trim => input.trim(),
+        }
+    }
+}
+
+/// Read the input as it is from the given path, or from stdin if the path is `-`.
+pub fn read_input(path: &Path) -> Result<String, InputError> {
+    let input = if path == Path::new(STDIN_INPUT) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    input.map_err(|err| InputError::Read {
+        source: input_source_name(path),
+        err,
+    })
+}
+
+/// Before the inputs were stored per year, the inputs of this year were stored directly in `inputs/`.
+const LEGACY_YEAR: u16 = 2023;
+
+/// Move an input from the old `inputs/day_XX.txt` location to the directory of its year.
+///
+/// The known answers are moved along with the first input.
+pub(crate) fn migrate_legacy_input(year: u16, file_name: &str, file_path: &str) {
+    // Only the inputs of the default inputs directory were stored there
+    if year != LEGACY_YEAR || inputs_dir() != Path::new(DEFAULT_INPUTS_DIR) {
+        return;
+    }
+
+    let legacy_path = Path::new(DEFAULT_INPUTS_DIR).join(file_name);
+    match move_legacy_input(
+        Path::new(DEFAULT_INPUTS_DIR),
+        file_name,
+        Path::new(file_path),
+    ) {
+        Ok(false) => {}
+        Ok(true) => eprintln!("Moved {} to {file_path}", legacy_path.display()),
+        Err(err) => eprintln!(
+            "Failed to move {} to {file_path}: {err}",
+            legacy_path.display()
+        ),
+    }
+}
+
+/// Move the input from the legacy directory to its path, returning whether it was moved.
+///
+/// Nothing is moved if there is no legacy input or the input already exists.
+fn move_legacy_input(legacy_dir: &Path, file_name: &str, file_path: &Path) -> io::Result<bool> {
+    let legacy_path = legacy_dir.join(file_name);
+    if file_path.exists() || !legacy_path.exists() {
+        return Ok(false);
+    }
+
+    let dir = file_path.parent().unwrap_or(Path::new(""));
+    fs::create_dir_all(dir)?;
+    fs::rename(&legacy_path, file_path)?;
+
+    let legacy_answers = legacy_dir.join("answers.toml");
+    let answers = dir.join("answers.toml");
+    if legacy_answers.exists() && !answers.exists() {
+        fs::rename(legacy_answers, answers)?;
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_move_legacy_input() {
+        let dir = env::temp_dir().join(format!("aoc_legacy_inputs_{}", std::process::id()));
+        let year_dir = dir.join("2023");
+        fs::create_dir_all(&dir).unwrap();
+
+        // The first input is moved, with the known answers
+        fs::write(dir.join("day_01.txt"), "legacy 1").unwrap();
+        fs::write(dir.join("answers.toml"), "answers").unwrap();
+        let day_01 = year_dir.join("day_01.txt");
+        assert!(move_legacy_input(&dir, "day_01.txt", &day_01).unwrap());
+        assert_eq!(fs::read_to_string(&day_01).unwrap(), "legacy 1");
+        assert_eq!(
+            fs::read_to_string(year_dir.join("answers.toml")).unwrap(),
+            "answers"
+        );
+        assert!(!dir.join("day_01.txt").exists());
+        assert!(!dir.join("answers.toml").exists());
+
+        // An input that is already in the directory of its year is not overwritten
+        fs::write(dir.join("day_02.txt"), "legacy 2").unwrap();
+        let day_02 = year_dir.join("day_02.txt");
+        fs::write(&day_02, "current 2").unwrap();
+        assert!(!move_legacy_input(&dir, "day_02.txt", &day_02).unwrap());
+        assert_eq!(fs::read_to_string(&day_02).unwrap(), "current 2");
+        assert_eq!(
+            fs::read_to_string(dir.join("day_02.txt")).unwrap(),
+            "legacy 2"
+        );
+
+        // Without a legacy input, nothing happens
+        let day_03 = year_dir.join("day_03.txt");
+        assert!(!move_legacy_input(&dir, "day_03.txt", &day_03).unwrap());
+        assert!(!day_03.exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_input_normalization() {
+        let input = "  #..#\n.##. \n\n";
+
+        assert_eq!(InputNormalization::Raw.apply(input), input);
+        assert_eq!(
+            InputNormalization::TrimTrailingNewline.apply(input),
+            "  #..#\n.##. \n"
+        );
+        assert_eq!(
+            InputNormalization::TrimTrailingNewline.apply("1 2\r\n"),
+            "1 2"
+        );
+        assert_eq!(InputNormalization::Trim.apply(input), "#..#\n.##.");
+    }
+}
@@ -0,0 +1,183 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::failure::PartFailure;
+use crate::input::InputError;
+use crate::runner::{DayReport, RunArgs, RunResult};
+use crate::table::{answer_cell, Table};
+use crate::OutputFormat;
+
+/// The extension of the input files in an inputs directory.
+const INPUT_EXTENSION: &str = "txt";
+
+/// The input files in a directory, sorted by name.
+pub fn input_files(dir: &Path) -> Result<Vec<PathBuf>, InputError> {
+    let read_error = |err| InputError::Read {
+        source: dir.display().to_string(),
+        err,
+    };
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+
+        if path.is_file() && path.extension().is_some_and(|ext| ext == INPUT_EXTENSION) {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Run a day against every input file in the directory.
+///
+/// The known answers are read from the `answers.toml` file in the directory.
+pub fn run_inputs_dir(
+    dir: &Path,
+    args: &RunArgs,
+    run: impl Fn(&RunArgs) -> DayReport,
+) -> Result<Vec<DayReport>, InputError> {
+    let files = input_files(dir)?;
+
+    if files.is_empty() {
+        return Err(InputError::Missing {
+            file_path: dir
+                .join(format!("*.{INPUT_EXTENSION}"))
+                .display()
+                .to_string(),
+        });
+    }
+
+    Ok(files
+        .into_iter()
+        .map(|file| {
+            run(&RunArgs {
+                input: Some(file),
+                inputs_dir: None,
+                ..args.clone()
+            })
+        })
+        .collect())
+}
+
+/// Print the reports of running a day against several inputs.
+///
+/// In the text format, the inputs are shown side by side in a table.
+pub fn print_inputs_reports(reports: &[DayReport], format: OutputFormat) {
+    if format != OutputFormat::Text {
+        for report in reports {
+            report.print(format);
+        }
+        return;
+    }
+
+    eprint!("{}", inputs_table(reports));
+}
+
+fn inputs_table(reports: &[DayReport]) -> String {
+    let mut table = Table::new(["INPUT", "PART 1", "PART 2", "ERROR"]).hide_empty_columns();
+    for report in reports {
+        table.push(
+            report.needs_attention(),
+            [
+                Path::new(&report.input_path)
+                    .file_name()
+                    .map_or(report.input_path.clone(), |name| {
+                        name.to_string_lossy().to_string()
+                    }),
+                part_cell(&report.part_1, &report.part_1_failure),
+                part_cell(&report.part_2, &report.part_2_failure),
+                report.error.clone().unwrap_or_default(),
+            ],
+        );
+    }
+
+    let mut output = String::new();
+    if let Some(report) = reports.first() {
+        output += &format!("DAY {:0>2}\n----\n", report.day);
+    }
+
+    let flagged = reports
+        .iter()
+        .filter(|report| report.needs_attention())
+        .count();
+    output += &table.render();
+    output += &format!("----\n{} inputs, {flagged} need attention\n", reports.len());
+
+    output
+}
+
+fn part_cell(result: &Option<RunResult>, failure: &Option<PartFailure>) -> String {
+    match (result, failure) {
+        (Some(result), _) => format!(
+            "{} [{:?}] ({})",
+            answer_cell(result),
+            result.time(),
+            result.status
+        ),
+        (None, Some(failure)) => failure.to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::AnswerStatus;
+    use crate::runner::fixtures::{self, result};
+
+    fn report(input: &str, part_1: Option<RunResult>, failure: Option<PartFailure>) -> DayReport {
+        DayReport {
+            input_path: format!("inputs/team/{input}"),
+            part_1,
+            part_1_failure: failure,
+            ..fixtures::report(8)
+        }
+    }
+
+    #[test]
+    fn test_inputs_table() {
+        let reports = [
+            report("alice.txt", Some(result("6", AnswerStatus::Verified)), None),
+            report(
+                "bob.txt",
+                Some(result(
+                    "7",
+                    AnswerStatus::Wrong {
+                        expected: "8".to_string(),
+                    },
+                )),
+                None,
+            ),
+            report("carol.txt", None, Some(PartFailure::NotImplemented)),
+        ];
+
+        assert_eq!(
+            inputs_table(&reports),
+            "DAY 08
+----
+    INPUT      PART 1
+    alice.txt  6 [2ms] (verified)
+!!  bob.txt    7 [2ms] (WRONG, expected 8)
+!!  carol.txt  not implemented
+----
+3 inputs, 2 need attention
+"
+        );
+    }
+
+    #[test]
+    fn test_input_files() {
+        let dir = std::env::temp_dir().join(format!("aoc_inputs_dir_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for file in ["bob.txt", "alice.txt", "answers.toml", "day_08.html"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let files = input_files(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(files, vec![dir.join("alice.txt"), dir.join("bob.txt")]);
+    }
+}
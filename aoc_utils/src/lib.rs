@@ -1,206 +1,330 @@
-use std::fmt::Debug;
-use std::fmt::Display;
-use std::fs;
-use std::hint::black_box;
-use std::panic;
+use std::num::NonZeroUsize;
 use std::path::Path;
-use std::time::Duration;
-use std::time::Instant;
+use std::process;
 
 use clap::Parser;
 use inquire::Confirm;
-use inquire::Text;
+
+pub use answer::AocAnswer;
+pub use answers::{AnswerStatus, AnswerStore, AnswerStoreError};
+pub use article::{parse_articles, Article};
+pub use baseline::{Baseline, BaselineComparison, BaselineError, DayBaseline, MachineInfo};
+pub use bench::{BenchOptions, BenchStats};
+pub use clock::{Clock, SystemClock};
+pub use download::{
+    check_session_cookie, download_and_save_input, download_input, download_puzzle_page,
+    save_input, DownloadError,
+};
+pub use duration::parse_duration;
+pub use failure::PartFailure;
+pub use html::extract_examples;
+pub use http::{
+    user_agent, HttpClient, HttpResponse, NetworkError, RateLimiter, ReqwestClient,
+    CONTACT_ENV_VAR, MIN_REQUEST_GAP,
+};
+use input::migrate_legacy_input;
+pub use input::{
+    input_file_path, input_source_name, puzzle_page_path, read_input, InputError,
+    InputNormalization, STDIN_INPUT,
+};
+pub use inputs_dir::{input_files, print_inputs_reports, run_inputs_dir};
+pub use memory::MemStats;
+use metadata::verify_input;
+pub use metadata::{sha256_hex, InputMetadata, MetadataError};
+pub use output::OutputFormat;
+pub use parallel::{default_jobs, run_parallel};
+pub use profiles::{
+    active_profile, inputs_dir, select_profile, Profile, ProfileError, ProfileStore,
+    CONFIG_DIR_ENV_VAR, DEFAULT_PROFILE,
+};
+pub use runner::{dyn_day, run_day, DayReport, DynAocDay, RunArgs, RunResult};
+pub use session::{
+    get_session_cookie, mark_session_expired, prompt_session_cookie, saved_session_cookie,
+    SessionError, SESSION_ENV_VAR,
+};
+pub use submit::{submit_answer, GuessVerdict, SubmitError, SubmitOutcome, WrongGuess};
+pub use summary::{summary_table, total_time, SortOrder};
+pub use unlock::{
+    download_with_retries, format_countdown, time_until_unlock, unlock_time, wait_for_unlock,
+};
+
+mod answer;
+mod answers;
+mod article;
+mod baseline;
+mod bench;
+mod clock;
+mod download;
+mod duration;
+mod examples;
+mod failure;
+mod html;
+mod http;
+mod input;
+mod inputs_dir;
+mod memory;
+mod metadata;
+mod output;
+mod parallel;
+mod profiles;
+mod runner;
+mod session;
+mod submit;
+mod summary;
+mod table;
+mod unlock;
 
 #[derive(Parser, Debug)]
 #[command()]
 struct Cli {
-    /// Run the first part of the puzzle
-    #[arg(long)]
-    part_1: bool,
-
-    /// Run the second part of the puzzle
-    #[arg(long)]
-    part_2: bool,
-
-    /// Benchmark the implementation
-    #[arg(long)]
-    bench: bool,
+    #[command(flatten)]
+    args: RunArgs,
 }
 
-pub trait AocDay<P1: Eq + Debug, P2: Eq + Debug> {
+pub trait AocDay<P1: AocAnswer, P2: AocAnswer> {
+    /// The year of the event that the solution is for.
+    const YEAR: u16 = 2023;
+
     /// The number of the day that the solution is for.
     const DAY: u8;
 
+    /// How the input is cleaned up before it is passed to [`AocDay::parse`].
+    ///
+    /// Puzzles where leading or trailing whitespace matters can use [`InputNormalization::Raw`].
+    const INPUT_NORMALIZATION: InputNormalization = InputNormalization::TrimTrailingNewline;
+
+    /// The input after it has been parsed, which is shared by both parts.
+    ///
+    /// Days that work on the raw input implement [`RawAocDay`] instead, which uses the input itself.
+    /// The parts may run on worker threads, so the parsed input must be [`Sync`].
+    type Parsed<'a>: Sync;
+
+    /// Parse the input once, before running the parts.
+    fn parse(input: &str) -> Self::Parsed<'_>;
+
     /// The implementation of the first part of the puzzle.
-    fn part_1(input: &str) -> P1;
+    fn part_1(input: &Self::Parsed<'_>) -> P1;
 
     /// The implementation of the second part of the puzzle.
-    fn part_2(input: &str) -> P2;
+    fn part_2(input: &Self::Parsed<'_>) -> P2;
 
     /// The name of the file for this day.
     fn get_file_name() -> String {
         format!("day_{:0>2}.txt", Self::DAY)
     }
 
-    /// The path to the input file for this day.
+    /// The path to the input file for this day, in the inputs directory of the profile in use.
     fn get_file_path() -> String {
-        format!("inputs/{}", Self::get_file_name())
+        input_file_path(Self::YEAR, Self::DAY)
+    }
+
+    /// The path to the cached description page of the puzzle.
+    fn get_puzzle_page_path() -> String {
+        puzzle_page_path(Self::YEAR, Self::DAY)
     }
 
-    /// Try to read the input from the file system.
-    fn get_input_from_file() -> Option<String> {
+    /// The path to an example input from the puzzle description, starting at 1.
+    fn get_example_file_path(example: usize) -> String {
+        format!(
+            "{}/{}/day_{:0>2}.example_{example}.txt",
+            inputs_dir().display(),
+            Self::YEAR,
+            Self::DAY
+        )
+    }
+
+    /// Try to read the input from the file system, as it is stored.
+    ///
+    /// Warns if the input differs from the one that was downloaded.
+    fn get_input_from_file() -> Result<Option<String>, InputError> {
         let file_path = Self::get_file_path();
+        migrate_legacy_input(Self::YEAR, &Self::get_file_name(), &file_path);
 
         if Path::new(&file_path).exists() {
-            Some(
-                fs::read_to_string(file_path.clone())
-                    .unwrap()
-                    .trim_end()
-                    .to_string(),
-            )
+            let input = read_input(Path::new(&file_path))?;
+            verify_input(Path::new(&file_path), &input);
+
+            Ok(Some(input))
         } else {
-            None
+            Ok(None)
         }
     }
 
-    /// Try to read the input from the file system.
-    fn download_and_save_input(session_cookie: &str) -> String {
-        let text = reqwest::blocking::Client::new()
-            .get(format!(
-                "https://adventofcode.com/2023/day/{}/input",
-                Self::DAY
-            ))
-            .header("Cookie", format!("session={session_cookie}"))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
-
-        let file_path = Self::get_file_path();
-        fs::write(file_path, text.clone()).unwrap();
+    /// Download the input from the server and save it to the file system.
+    ///
+    /// The input is not requested before the puzzle unlocks.
+    fn download_and_save_input(
+        client: &dyn HttpClient,
+        base_url: &str,
+        session_cookie: &str,
+    ) -> Result<String, DownloadError> {
+        if let Some(left) = time_until_unlock(&SystemClock, Self::YEAR, Self::DAY) {
+            return Err(DownloadError::NotUnlocked { left });
+        }
 
-        text
+        download_and_save_input(
+            client,
+            base_url,
+            Self::YEAR,
+            Self::DAY,
+            session_cookie,
+            Path::new(&Self::get_file_path()),
+        )
     }
 
-    fn get_input() -> String {
-        if let Some(input) = Self::get_input_from_file() {
-            return input;
+    /// Get the input, either from the file system or by downloading it.
+    ///
+    /// If `interactive` is set, the user is asked before downloading the input.
+    /// Otherwise, the input is only downloaded if a session cookie is available.
+    fn get_input(base_url: &str, interactive: bool) -> Result<String, InputError> {
+        if let Some(input) = Self::get_input_from_file()? {
+            return Ok(input);
         }
 
         let file_path = Self::get_file_path();
 
-        eprintln!();
+        if interactive {
+            eprintln!();
 
-        if !Confirm::new(&format!(
-            "The input file {file_path} does not exist. Do you want to download it?"
-        ))
-        .prompt()
-        .unwrap()
-        {
-            panic!("No input provided! You can add it at {file_path}.")
+            if !Confirm::new(&format!(
+                "The input file {file_path} does not exist. Do you want to download it?"
+            ))
+            .prompt()?
+            {
+                return Err(InputError::Missing { file_path });
+            }
+        } else if saved_session_cookie().is_none() {
+            return Err(InputError::PromptDisabled { file_path });
         }
 
-        let session_cookie = get_session_cookie();
-        Self::download_and_save_input(&session_cookie)
+        let session_cookie = get_session_cookie(interactive)?;
+        let input = Self::download_and_save_input(&ReqwestClient::new(), base_url, &session_cookie)
+            .inspect_err(|err| {
+                if matches!(err, DownloadError::SessionExpired) {
+                    mark_session_expired();
+                }
+            })?;
+
+        Ok(input)
     }
 
-    fn run() {
-        let start = Instant::now();
-        let cli = Cli::parse();
+    /// Get an example input from the puzzle description, starting at 1.
+    ///
+    /// The examples are extracted from the cached description page, which is downloaded
+    /// if it is not cached yet.
+    fn get_example(base_url: &str, example: NonZeroUsize) -> Result<String, InputError> {
+        let example_path = Self::get_example_file_path(example.get());
 
-        let (part_1, part_2) = if !cli.part_1 && !cli.part_2 {
-            (true, true)
+        if Path::new(&example_path).exists() {
+            return read_input(Path::new(&example_path));
+        }
+
+        let page_path = Self::get_puzzle_page_path();
+        let examples = if Path::new(&page_path).exists() {
+            extract_examples(&read_input(Path::new(&page_path))?)
         } else {
-            (cli.part_1, cli.part_2)
+            if let Some(left) = time_until_unlock(&SystemClock, Self::YEAR, Self::DAY) {
+                return Err(DownloadError::NotUnlocked { left }.into());
+            }
+
+            let page = download_puzzle_page(
+                &ReqwestClient::new(),
+                base_url,
+                Self::YEAR,
+                Self::DAY,
+                saved_session_cookie().as_deref(),
+            )?;
+            save_input(Path::new(&page_path), &page)?;
+            extract_examples(&page)
         };
 
-        eprintln!("DAY {:0>2}\n----", Self::DAY);
-        eprint!("INPUT: ");
-        let start_input = Instant::now();
-        let input = Self::get_input();
-        let time_input = start_input.elapsed();
-        eprintln!(
-            "{} ({} lines) [{:?}]",
-            Self::get_file_path(),
-            input.lines().count(),
-            time_input
-        );
-
-        if part_1 {
-            eprint!("PART 1: ");
-            eprintln!("{}", run_part(Self::part_1, &input, cli.bench));
-        }
-
-        if part_2 {
-            eprint!("PART 2: ");
-            eprintln!("{}", run_part(Self::part_2, &input, cli.bench));
+        for (idx, content) in examples.iter().enumerate() {
+            save_input(Path::new(&Self::get_example_file_path(idx + 1)), content)?;
         }
 
-        let time = start.elapsed();
-        eprintln!("----\nFinished in {:?}", time);
+        examples
+            .get(example.get() - 1)
+            .cloned()
+            .ok_or(InputError::ExampleNotFound {
+                example: example.get(),
+                count: examples.len(),
+                day: Self::DAY,
+            })
     }
-}
 
-struct RunResult<T: Debug> {
-    res: T,
-    times: Vec<Duration>,
-}
+    fn run()
+    where
+        Self: Sized + 'static,
+        P1: 'static,
+        P2: 'static,
+    {
+        let cli = Cli::parse();
 
-impl<T: Debug> Display for RunResult<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.times.len() == 1 {
-            write!(f, "{:?} [{:?}]", self.res, self.times[0])
+        let failed = if let Some(dir) = &cli.args.inputs_dir {
+            match run_inputs_dir(dir, &cli.args, run_day::<Self, P1, P2>) {
+                Ok(reports) => {
+                    print_inputs_reports(&reports, cli.args.format);
+                    reports.iter().any(DayReport::needs_attention)
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    true
+                }
+            }
         } else {
-            let avg = self.times.iter().sum::<Duration>() / self.times.len() as u32;
-            let min = self.times.iter().min().unwrap();
-            let max = self.times.iter().max().unwrap();
-            let deviation = (avg - *min).max(*max - avg);
-
-            write!(
-                f,
-                "{:?} [{:?} ± {:?}, {} samples]",
-                self.res,
-                avg,
-                deviation,
-                self.times.len()
-            )
+            let report = run_day::<Self, P1, P2>(&cli.args);
+            report.print(cli.args.format);
+            report.failed()
+        };
+
+        if failed {
+            process::exit(1);
         }
     }
 }
 
-fn run_part<T: Eq + Debug>(part: impl Fn(&str) -> T, input: &str, bench: bool) -> RunResult<T> {
-    let mut times = Vec::new();
+/// A solution that works on the input as it is, without a parse step.
+///
+/// Every `RawAocDay` is an [`AocDay`] whose parsed input is the input itself,
+/// so the parts take the input as `&str`.
+pub trait RawAocDay<P1: AocAnswer, P2: AocAnswer> {
+    /// The year of the event that the solution is for.
+    const YEAR: u16 = 2023;
 
-    let start = Instant::now();
-    let res = part(black_box(input));
-    times.push(start.elapsed());
+    /// The number of the day that the solution is for.
+    const DAY: u8;
 
-    if bench {
-        while start.elapsed() < Duration::from_secs(5) {
-            let iter_start = Instant::now();
-            let iter_res = part(black_box(input));
-            times.push(iter_start.elapsed());
-            assert_eq!(res, iter_res);
-        }
-    }
+    /// How the input is cleaned up before it is passed to the parts.
+    const INPUT_NORMALIZATION: InputNormalization = InputNormalization::TrimTrailingNewline;
+
+    /// The implementation of the first part of the puzzle.
+    fn part_1(input: &str) -> P1;
 
-    RunResult { res, times }
+    /// The implementation of the second part of the puzzle.
+    fn part_2(input: &str) -> P2;
 }
 
-fn get_session_cookie() -> String {
-    let saved_cookie_path = Path::new("inputs/.session_cookie");
+impl<D, P1, P2> AocDay<P1, P2> for D
+where
+    D: RawAocDay<P1, P2>,
+    P1: AocAnswer,
+    P2: AocAnswer,
+{
+    const YEAR: u16 = <D as RawAocDay<P1, P2>>::YEAR;
+    const DAY: u8 = <D as RawAocDay<P1, P2>>::DAY;
+    const INPUT_NORMALIZATION: InputNormalization = <D as RawAocDay<P1, P2>>::INPUT_NORMALIZATION;
 
-    if saved_cookie_path.exists() {
-        fs::read_to_string("inputs/.session_cookie")
-            .unwrap()
-            .trim()
-            .to_string()
-    } else {
-        let session_cookie = Text::new("What is your Advent of Code session cookie?")
-        .with_help_message("You can get your session cookie on https://adventofcode.com using your browsers dev tools.").prompt().unwrap().trim().to_string();
+    type Parsed<'a> = &'a str;
 
-        fs::write(saved_cookie_path, session_cookie.clone()).unwrap();
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_1(input: &&str) -> P1 {
+        <D as RawAocDay<P1, P2>>::part_1(input)
+    }
 
-        session_cookie
+    fn part_2(input: &&str) -> P2 {
+        <D as RawAocDay<P1, P2>>::part_2(input)
     }
 }
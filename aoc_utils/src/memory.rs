@@ -0,0 +1,168 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// The memory used while running a part of the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct MemStats {
+    /// The number of allocations, including reallocations.
+    pub allocations: u64,
+    /// The total number of bytes allocated.
+    pub allocated_bytes: u64,
+    /// The highest number of bytes allocated at the same time, on top of what was allocated before.
+    pub peak_bytes: u64,
+}
+
+impl Display for MemStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.allocated_bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Whether the memory usage can be measured, i.e. the counting allocator is enabled.
+pub const MEASURE_MEMORY: bool = cfg!(feature = "mem");
+
+/// Run the function and measure the memory it uses.
+///
+/// Returns no statistics if the `mem` feature is disabled.
+/// The allocations of all threads are counted, so other threads should be idle.
+pub fn measure_memory<T>(run: impl FnOnce() -> T) -> (T, Option<MemStats>) {
+    #[cfg(feature = "mem")]
+    {
+        let (res, stats) = counting::measure(run);
+        (res, Some(stats))
+    }
+
+    #[cfg(not(feature = "mem"))]
+    {
+        (run(), None)
+    }
+}
+
+/// A global allocator that counts the allocations, enabled by the `mem` feature.
+#[cfg(feature = "mem")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use super::MemStats;
+
+    static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+    static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+    static CURRENT_BYTES: AtomicU64 = AtomicU64::new(0);
+    static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn record_allocation(size: u64) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record_allocation(layout.size() as u64);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record_allocation(layout.size() as u64);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            CURRENT_BYTES.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                // Count it as a new allocation, followed by freeing the old one
+                record_allocation(new_size as u64);
+                CURRENT_BYTES.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+            }
+            new_ptr
+        }
+    }
+
+    pub fn measure<T>(run: impl FnOnce() -> T) -> (T, MemStats) {
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+        let current_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+        PEAK_BYTES.store(current_bytes, Ordering::Relaxed);
+
+        let res = run();
+
+        let stats = MemStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+            peak_bytes: PEAK_BYTES
+                .load(Ordering::Relaxed)
+                .saturating_sub(current_bytes),
+        };
+
+        (res, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[cfg(feature = "mem")]
+    #[test]
+    fn test_measure_memory() {
+        let (_, stats) = measure_memory(|| {
+            let small = vec![0u8; 1000];
+            drop(small);
+            vec![0u8; 4000]
+        });
+        let stats = stats.unwrap();
+
+        assert!(stats.allocations >= 2);
+        assert!(stats.allocated_bytes >= 5000);
+        assert!(stats.peak_bytes >= 4000);
+    }
+}
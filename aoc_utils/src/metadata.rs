@@ -0,0 +1,150 @@
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// The extension of the metadata file, which replaces the extension of the input file.
+const METADATA_EXTENSION: &str = "meta.toml";
+
+/// Where an input came from, stored next to it to detect corrupted or edited inputs.
+///
+/// For `inputs/2023/day_05.txt`, the metadata is stored in `inputs/2023/day_05.meta.toml`:
+///
+/// ```toml
+/// source = "https://adventofcode.com/2023/day/5/input"
+/// downloaded_at = 1701406803
+/// sha256 = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputMetadata {
+    /// Where the input was downloaded from.
+    pub source: String,
+    /// When the input was downloaded, in seconds since the Unix epoch.
+    pub downloaded_at: u64,
+    /// The SHA-256 hash of the input file, in hex.
+    pub sha256: String,
+}
+
+/// The reasons why the metadata of an input could not be loaded or saved.
+#[derive(Debug)]
+pub enum MetadataError {
+    Io { path: PathBuf, err: io::Error },
+    Parse { path: PathBuf, err: toml::de::Error },
+}
+
+impl Display for MetadataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, err } => write!(f, "Failed to access {}: {err}", path.display()),
+            Self::Parse { path, err } => write!(f, "Failed to parse {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for MetadataError {}
+
+impl InputMetadata {
+    /// The metadata of an input that was just downloaded.
+    pub fn new(source: &str, input: &str) -> Self {
+        Self {
+            source: source.to_string(),
+            downloaded_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_secs()),
+            sha256: sha256_hex(input),
+        }
+    }
+
+    /// The path of the metadata for the given input file.
+    pub fn path_for_input(input_path: &Path) -> PathBuf {
+        input_path.with_extension(METADATA_EXTENSION)
+    }
+
+    /// Load the metadata from the given path, or `None` if there is none.
+    pub fn load(path: &Path) -> Result<Option<Self>, MetadataError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path).map_err(|err| MetadataError::Io {
+            path: path.to_path_buf(),
+            err,
+        })?;
+
+        toml::from_str(&content)
+            .map(Some)
+            .map_err(|err| MetadataError::Parse {
+                path: path.to_path_buf(),
+                err,
+            })
+    }
+
+    /// Save the metadata to the given path.
+    pub fn save(&self, path: &Path) -> Result<(), MetadataError> {
+        let content = toml::to_string(self).expect("Failed to serialize input metadata");
+
+        fs::write(path, content).map_err(|err| MetadataError::Io {
+            path: path.to_path_buf(),
+            err,
+        })
+    }
+
+    /// Whether the input is still the one that was downloaded.
+    pub fn matches(&self, input: &str) -> bool {
+        self.sha256 == sha256_hex(input)
+    }
+}
+
+/// The SHA-256 hash of the text, in hex.
+pub fn sha256_hex(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Warn if the input file differs from the input that was downloaded.
+pub(crate) fn verify_input(input_path: &Path, input: &str) {
+    match InputMetadata::load(&InputMetadata::path_for_input(input_path)) {
+        Ok(Some(metadata)) if !metadata.matches(input) => eprintln!(
+            "Warning: {} differs from the input downloaded from {}, it may be corrupted or edited. Delete it to download it again.",
+            input_path.display(),
+            metadata.source
+        ),
+        Ok(_) => {}
+        Err(err) => eprintln!("Warning: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_metadata() {
+        let input = "0 3 6 9 12 15\n";
+        let metadata = InputMetadata::new("https://adventofcode.com/2023/day/9/input", input);
+
+        assert_eq!(metadata.source, "https://adventofcode.com/2023/day/9/input");
+        assert_eq!(metadata.sha256.len(), 64);
+        assert!(metadata.matches(input));
+        assert!(!metadata.matches("0 3 6 9 12 15"));
+
+        assert_eq!(
+            InputMetadata::path_for_input(Path::new("inputs/2023/day_09.txt")),
+            Path::new("inputs/2023/day_09.meta.toml")
+        );
+    }
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex("test"),
+            "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+        );
+    }
+}
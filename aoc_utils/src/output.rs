@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serializer;
+
+use crate::DayReport;
+
+/// How the results of a run are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// A human readable report on stderr
+    #[default]
+    Text,
+
+    /// One JSON record per day on stdout
+    Json,
+
+    /// Only the answers on stdout, one `day<TAB>part<TAB>answer` line per part
+    Answers,
+}
+
+/// The placeholder for a part without an answer in the answers format.
+const MISSING_ANSWER: &str = "-";
+
+impl DayReport {
+    /// Print the report in the given format.
+    ///
+    /// Errors are always printed to stderr, so that stdout only contains the requested output.
+    pub fn print(&self, format: OutputFormat) {
+        match format {
+            OutputFormat::Text => eprint!("{self}"),
+            OutputFormat::Json => println!(
+                "{}",
+                serde_json::to_string(self).expect("Failed to serialize report")
+            ),
+            OutputFormat::Answers => {
+                for line in self.answer_lines() {
+                    println!("{line}");
+                }
+
+                for result in [&self.part_1, &self.part_2].into_iter().flatten() {
+                    if result.answer.is_none() {
+                        eprintln!("DAY {:0>2}: {}", self.day, result.display);
+                    }
+                }
+
+                for (part, failure) in [(1, &self.part_1_failure), (2, &self.part_2_failure)] {
+                    if let Some(failure) = failure {
+                        eprintln!("DAY {:0>2} PART {part}: {failure}", self.day);
+                    }
+                }
+
+                if let Some(error) = &self.error {
+                    eprintln!("DAY {:0>2}: {error}", self.day);
+                }
+            }
+        }
+    }
+
+    /// The answers of the parts that were run, as `day<TAB>part<TAB>answer` lines.
+    ///
+    /// Parts without an answer, because they are unsolved or failed, show [`MISSING_ANSWER`].
+    fn answer_lines(&self) -> Vec<String> {
+        [
+            (1, &self.part_1, &self.part_1_failure),
+            (2, &self.part_2, &self.part_2_failure),
+        ]
+        .into_iter()
+        .filter(|(_, result, failure)| result.is_some() || failure.is_some())
+        .map(|(part, result, _)| {
+            let answer = result
+                .as_ref()
+                .and_then(|result| result.answer.as_deref())
+                .unwrap_or(MISSING_ANSWER);
+            format!("{}\t{part}\t{answer}", self.day)
+        })
+        .collect()
+    }
+}
+
+pub(crate) fn serialize_duration<S: Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+pub(crate) fn serialize_durations<S: Serializer>(
+    durations: &[Duration],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(durations.iter().map(Duration::as_nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::fixtures;
+    use crate::{AnswerStatus, PartFailure, RunResult};
+
+    #[test]
+    fn test_json_report() {
+        let report = DayReport {
+            year: 2023,
+            day: 1,
+            input_path: "inputs/2023/day_01.txt".to_string(),
+            input_lines: Some(4),
+            input_time: Duration::from_nanos(10),
+            parse_time: Duration::from_nanos(5),
+            part_1: Some(RunResult {
+                answer: Some("142".to_string()),
+                display: "142".to_string(),
+                cold_time: Duration::from_nanos(15),
+                stats: None,
+                comparison: None,
+                memory: None,
+                times: vec![Duration::from_nanos(20), Duration::from_nanos(30)],
+                status: AnswerStatus::Wrong {
+                    expected: "143".to_string(),
+                },
+                submission: None,
+            }),
+            part_2: None,
+            part_1_failure: None,
+            part_2_failure: None,
+            error: None,
+            time: Duration::from_nanos(100),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"year":2023,"day":1,"input_path":"inputs/2023/day_01.txt","input_lines":4,"input_time_ns":10,"parse_time_ns":5,"part_1":{"answer":"142","cold_time_ns":15,"times_ns":[20,30],"status":"wrong","expected":"143"},"part_2":null,"error":null,"time_ns":100}"#
+        );
+    }
+
+    #[test]
+    fn test_answer_lines() {
+        let report = DayReport {
+            part_1: Some(fixtures::result("35", AnswerStatus::Verified)),
+            part_2_failure: Some(PartFailure::NotImplemented),
+            ..fixtures::report(5)
+        };
+        assert_eq!(report.answer_lines(), ["5\t1\t35", "5\t2\t-"]);
+
+        let only_part_2 = DayReport {
+            part_2: Some(fixtures::result("46", AnswerStatus::Unknown)),
+            ..fixtures::report(5)
+        };
+        assert_eq!(only_part_2.answer_lines(), ["5\t2\t46"]);
+    }
+}
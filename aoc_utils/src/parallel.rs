@@ -0,0 +1,131 @@
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::runner::{DayReport, DynAocDay, RunArgs};
+
+/// The number of threads to use by default, one per available CPU.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Run the days on a pool of `jobs` threads.
+///
+/// `on_report` is called with each report as soon as its day has finished, one report at a time,
+/// so the reports can be printed without interleaving.
+/// The reports are returned in the order of the days.
+///
+/// The days never prompt for anything, as the prompts of several days would be mixed up.
+pub fn run_parallel(
+    days: &[Box<dyn DynAocDay>],
+    args: &RunArgs,
+    jobs: usize,
+    on_report: impl Fn(&DayReport) + Sync,
+) -> Vec<DayReport> {
+    let args = RunArgs {
+        no_prompt: true,
+        ..args.clone()
+    };
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(vec![None; days.len()]);
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                // Take the next day that hasn't been started yet, until all days are done
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(day) = days.get(idx) else {
+                        break;
+                    };
+                    let report = day.run(&args);
+
+                    let mut reports = reports.lock().unwrap_or_else(|err| err.into_inner());
+                    on_report(&report);
+                    reports[idx] = Some(report);
+                }
+            });
+        }
+    });
+
+    reports
+        .into_inner()
+        .unwrap_or_else(|err| err.into_inner())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::runner::fixtures;
+
+    /// A day that takes longer the earlier it is and counts how many days run at the same time.
+    struct SlowDay {
+        day: u8,
+        running: &'static AtomicUsize,
+        max_running: &'static AtomicUsize,
+    }
+
+    impl DynAocDay for SlowDay {
+        fn year(&self) -> u16 {
+            2023
+        }
+
+        fn day(&self) -> u8 {
+            self.day
+        }
+
+        fn input_path(&self) -> String {
+            format!("inputs/2023/day_{:0>2}.txt", self.day)
+        }
+
+        fn unsolved_parts(&self) -> [bool; 2] {
+            [false; 2]
+        }
+
+        fn run(&self, args: &RunArgs) -> DayReport {
+            assert!(args.no_prompt, "Expected the days not to prompt");
+
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(running, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(10 * u64::from(5 - self.day)));
+            self.running.fetch_sub(1, Ordering::SeqCst);
+
+            DayReport {
+                input_lines: None,
+                ..fixtures::report(self.day)
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_parallel() {
+        static RUNNING: AtomicUsize = AtomicUsize::new(0);
+        static MAX_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+        let days: Vec<Box<dyn DynAocDay>> = (1..=4)
+            .map(|day| {
+                Box::new(SlowDay {
+                    day,
+                    running: &RUNNING,
+                    max_running: &MAX_RUNNING,
+                }) as Box<dyn DynAocDay>
+            })
+            .collect();
+        let finished = Mutex::new(Vec::new());
+
+        let reports = run_parallel(&days, &RunArgs::default(), 2, |report| {
+            finished.lock().unwrap().push(report.day)
+        });
+
+        let days: Vec<_> = reports.iter().map(|report| report.day).collect();
+        assert_eq!(days, [1, 2, 3, 4]);
+        assert_eq!(finished.into_inner().unwrap().len(), 4);
+        assert_eq!(MAX_RUNNING.load(Ordering::SeqCst), 2);
+    }
+}
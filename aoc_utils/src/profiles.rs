@@ -0,0 +1,363 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+/// The environment variable that can override the directory of the configuration.
+pub const CONFIG_DIR_ENV_VAR: &str = "AOC_CONFIG_DIR";
+
+/// The name of the file storing the profiles, in the configuration directory.
+const PROFILES_FILE_NAME: &str = "profiles.toml";
+
+/// The profile that is created when the first session cookie is saved without choosing one.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// The directory of the inputs when no profile is configured.
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+/// The profile selected with `--profile`, overriding the default one of the store.
+static SELECTED_PROFILE: Mutex<Option<String>> = Mutex::new(None);
+
+/// The profile store of the user, once it has been loaded.
+static LOADED_STORE: Mutex<Option<ProfileStore>> = Mutex::new(None);
+
+/// The session cookies of several accounts, stored in the user configuration directory:
+///
+/// ```toml
+/// default = "alice"
+///
+/// [profiles.alice]
+/// session_cookie = "53616c7465645f5f..."
+/// inputs_dir = "inputs"
+///
+/// [profiles.bob]
+/// session_cookie = "53616c7465645f5f..."
+/// inputs_dir = "inputs/bob"
+/// expired = true
+/// ```
+///
+/// The file is only readable by the user, since the cookies give access to the accounts.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileStore {
+    /// The profile that is used unless another one is selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// The session cookie of an account and where its inputs are stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub session_cookie: String,
+    /// The directory containing the inputs of the account, e.g. `inputs/2023/day_01.txt`.
+    pub inputs_dir: PathBuf,
+    /// Whether the server rejected the session cookie the last time it was used.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub expired: bool,
+}
+
+impl Profile {
+    /// A profile with the inputs in `inputs/` for the default profile and `inputs/<name>/` otherwise.
+    pub fn new(name: &str, session_cookie: &str) -> Self {
+        let inputs_dir = if name == DEFAULT_PROFILE {
+            PathBuf::from(DEFAULT_INPUTS_DIR)
+        } else {
+            Path::new(DEFAULT_INPUTS_DIR).join(name)
+        };
+
+        Self {
+            session_cookie: session_cookie.to_string(),
+            inputs_dir,
+            expired: false,
+        }
+    }
+}
+
+/// The reasons why the profiles could not be loaded or saved.
+#[derive(Debug)]
+pub enum ProfileError {
+    /// The configuration directory of the user is unknown.
+    NoConfigDir,
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        err: toml::de::Error,
+    },
+    /// The selected profile does not exist.
+    Unknown {
+        name: String,
+    },
+}
+
+impl Display for ProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoConfigDir => write!(
+                f,
+                "Could not find the configuration directory, set the {CONFIG_DIR_ENV_VAR} environment variable"
+            ),
+            Self::Io { path, err } => write!(f, "Failed to access {}: {err}", path.display()),
+            Self::Parse { path, err } => write!(f, "Failed to parse {}: {err}", path.display()),
+            Self::Unknown { name } => write!(
+                f,
+                "There is no profile named {name:?}, add it with `aoc auth login --profile {name}`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {}
+
+impl ProfileStore {
+    /// The path of the profile store in the configuration directory of the user.
+    pub fn default_path() -> Result<PathBuf, ProfileError> {
+        let dir = match env::var_os(CONFIG_DIR_ENV_VAR) {
+            Some(dir) => PathBuf::from(dir),
+            None => dirs::config_dir()
+                .ok_or(ProfileError::NoConfigDir)?
+                .join("aoc"),
+        };
+
+        Ok(dir.join(PROFILES_FILE_NAME))
+    }
+
+    /// Load the profile store from the given path.
+    ///
+    /// If the file doesn't exist yet, the store is empty.
+    pub fn load(path: &Path) -> Result<Self, ProfileError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path).map_err(|err| ProfileError::Io {
+            path: path.to_path_buf(),
+            err,
+        })?;
+
+        toml::from_str(&content).map_err(|err| ProfileError::Parse {
+            path: path.to_path_buf(),
+            err,
+        })
+    }
+
+    /// Save the profile store to the given path, readable only by the user.
+    pub fn save(&self, path: &Path) -> Result<(), ProfileError> {
+        let content = toml::to_string(self).expect("Failed to serialize profiles");
+
+        write_private(path, &content).map_err(|err| ProfileError::Io {
+            path: path.to_path_buf(),
+            err,
+        })
+    }
+
+    /// The name and profile to use, either the selected one or the default one.
+    pub fn active(&self, selected: Option<&str>) -> Result<Option<(&str, &Profile)>, ProfileError> {
+        let Some(name) = selected.or(self.default.as_deref()) else {
+            return Ok(None);
+        };
+
+        self.profiles
+            .get_key_value(name)
+            .map(|(name, profile)| Some((name.as_str(), profile)))
+            .ok_or_else(|| ProfileError::Unknown {
+                name: name.to_string(),
+            })
+    }
+
+    /// Add or replace a profile, making it the default if it is the first one.
+    pub fn insert(&mut self, name: &str, profile: Profile) {
+        self.profiles.insert(name.to_string(), profile);
+
+        if self.default.is_none() {
+            self.default = Some(name.to_string());
+        }
+    }
+
+    /// Remove a profile, choosing another default if it was the default one.
+    pub fn remove(&mut self, name: &str) -> Option<Profile> {
+        let profile = self.profiles.remove(name)?;
+
+        if self.default.as_deref() == Some(name) {
+            self.default = self.profiles.keys().next().cloned();
+        }
+
+        Some(profile)
+    }
+}
+
+/// Write the file so that only the user can read it, creating the parent directories if needed.
+fn write_private(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+        options.mode(0o600);
+        // The mode only applies to new files, so fix the permissions of existing ones
+        if path.exists() {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    options.open(path)?.write_all(content.as_bytes())
+}
+
+/// Select the profile to use instead of the default one, e.g. from `--profile`.
+///
+/// Fails if there is no profile with that name.
+pub fn select_profile(name: &str) -> Result<(), ProfileError> {
+    with_loaded_store(|store| store.active(Some(name)).map(|_| ()))?;
+
+    *SELECTED_PROFILE.lock().unwrap() = Some(name.to_string());
+    Ok(())
+}
+
+/// The name of the profile selected with [`select_profile`], if any.
+pub fn selected_profile() -> Option<String> {
+    SELECTED_PROFILE.lock().unwrap().clone()
+}
+
+/// The name and settings of the profile in use, if any profiles are configured.
+pub fn active_profile() -> Result<Option<(String, Profile)>, ProfileError> {
+    let selected = selected_profile();
+
+    with_loaded_store(|store| {
+        Ok(store
+            .active(selected.as_deref())?
+            .map(|(name, profile)| (name.to_string(), profile.clone())))
+    })
+}
+
+/// Look something up in the profile store of the user.
+///
+/// The store is needed for every input path, so it is only loaded once per run.
+fn with_loaded_store<T>(
+    f: impl FnOnce(&ProfileStore) -> Result<T, ProfileError>,
+) -> Result<T, ProfileError> {
+    let mut loaded = LOADED_STORE.lock().unwrap_or_else(|err| err.into_inner());
+
+    let store = match &mut *loaded {
+        Some(store) => store,
+        None => loaded.insert(ProfileStore::load(&ProfileStore::default_path()?)?),
+    };
+
+    f(store)
+}
+
+/// Forget the loaded profile store, so it is loaded again, e.g. from another configuration directory.
+#[cfg(test)]
+pub(crate) fn forget_loaded_store() {
+    *LOADED_STORE.lock().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+/// The directory containing the inputs of the profile in use.
+///
+/// Falls back to `inputs/` if no profile is configured or the profiles can't be loaded.
+pub fn inputs_dir() -> PathBuf {
+    match active_profile() {
+        Ok(Some((_, profile))) => profile.inputs_dir,
+        _ => PathBuf::from(DEFAULT_INPUTS_DIR),
+    }
+}
+
+/// Update the profile in use and save the store, e.g. to record that its cookie has expired.
+///
+/// If no profile is configured, the [`DEFAULT_PROFILE`] is created with the given cookie.
+pub fn update_active_profile(
+    session_cookie: &str,
+    update: impl FnOnce(&mut Profile),
+) -> Result<String, ProfileError> {
+    let path = ProfileStore::default_path()?;
+    let mut store = ProfileStore::load(&path)?;
+    let selected = selected_profile();
+    let name = store
+        .active(selected.as_deref())?
+        .map_or(DEFAULT_PROFILE.to_string(), |(name, _)| name.to_string());
+
+    let profile = store
+        .profiles
+        .entry(name.clone())
+        .or_insert_with(|| Profile::new(&name, session_cookie));
+    update(profile);
+
+    if store.default.is_none() {
+        store.default = Some(name.clone());
+    }
+
+    store.save(&path)?;
+    *LOADED_STORE.lock().unwrap_or_else(|err| err.into_inner()) = Some(store);
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_active_profile() {
+        let mut store = ProfileStore::default();
+        assert_eq!(store.active(None).unwrap(), None);
+
+        store.insert("alice", Profile::new("alice", "abc"));
+        store.insert(DEFAULT_PROFILE, Profile::new(DEFAULT_PROFILE, "def"));
+
+        let (name, profile) = store.active(None).unwrap().unwrap();
+        assert_eq!(name, "alice");
+        assert_eq!(profile.inputs_dir, Path::new("inputs").join("alice"));
+
+        let (name, profile) = store.active(Some(DEFAULT_PROFILE)).unwrap().unwrap();
+        assert_eq!(name, DEFAULT_PROFILE);
+        assert_eq!(profile.inputs_dir, Path::new("inputs"));
+
+        assert!(matches!(
+            store.active(Some("bob")),
+            Err(ProfileError::Unknown { .. })
+        ));
+
+        store.remove("alice");
+        assert_eq!(store.default.as_deref(), Some(DEFAULT_PROFILE));
+    }
+
+    #[test]
+    fn test_save_profiles() {
+        let dir = env::temp_dir().join(format!("aoc_profiles_{}", std::process::id()));
+        let path = dir.join(PROFILES_FILE_NAME);
+
+        let mut store = ProfileStore::default();
+        store.insert("alice", Profile::new("alice", "abc"));
+        store.save(&path).unwrap();
+
+        let loaded = ProfileStore::load(&path).unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded, store);
+        assert_eq!(
+            toml::to_string(&store).unwrap(),
+            "default = \"alice\"\n\n[profiles.alice]\nsession_cookie = \"abc\"\ninputs_dir = \"inputs/alice\"\n"
+        );
+    }
+}
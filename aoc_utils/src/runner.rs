@@ -0,0 +1,987 @@
+use std::any::TypeId;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::io::{self, IsTerminal};
+use std::marker::PhantomData;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use clap::Args;
+use serde::Serialize;
+
+use crate::answer::AocAnswer;
+use crate::answers::{AnswerStatus, AnswerStore, AnswerStoreError};
+use crate::baseline::{Baseline, BaselineComparison, BaselineError, DayBaseline};
+use crate::bench::{benchmark, BenchOptions, BenchStats};
+use crate::duration::parse_duration;
+use crate::failure::{catch_failure, PartFailure};
+use crate::http::HttpClient;
+use crate::input::{input_source_name, read_input, STDIN_INPUT};
+use crate::memory::{measure_memory, MemStats, MEASURE_MEMORY};
+use crate::output::{serialize_duration, serialize_durations};
+use crate::session::SessionError;
+use crate::submit::{submit_answer, SubmitError, SubmitOutcome, WrongGuess};
+use crate::OutputFormat;
+use crate::{get_session_cookie, select_profile, AocDay, ReqwestClient};
+
+/// The options for running the solution of a day.
+#[derive(Args, Debug, Clone, Default)]
+pub struct RunArgs {
+    /// Run the first part of the puzzle
+    #[arg(long)]
+    pub part_1: bool,
+
+    /// Run the second part of the puzzle
+    #[arg(long)]
+    pub part_2: bool,
+
+    /// Benchmark the implementation
+    #[arg(long)]
+    pub bench: bool,
+
+    /// How long to measure each part when benchmarking, e.g. `5s` or `500ms`
+    #[arg(long, value_name = "DURATION", default_value = "5s", value_parser = parse_duration)]
+    pub bench_time: Duration,
+
+    /// How long to run each part before measuring it when benchmarking
+    #[arg(long, value_name = "DURATION", default_value = "1s", value_parser = parse_duration)]
+    pub warmup: Duration,
+
+    /// Report the allocations and the peak memory of each part (requires the `mem` feature)
+    #[arg(long)]
+    pub mem: bool,
+
+    /// Give up on a part if its first run takes longer than this, e.g. `30s`;
+    /// parsing the input and the benchmark runs after the first one are not limited
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+
+    /// The maximum number of samples to take when benchmarking
+    #[arg(long, value_name = "N")]
+    pub samples: Option<usize>,
+
+    /// Save the benchmark results as a baseline with this name
+    #[arg(long, value_name = "NAME", requires = "bench")]
+    pub save_baseline: Option<String>,
+
+    /// Compare the benchmark results against the baseline with this name
+    #[arg(long, value_name = "NAME", requires = "bench")]
+    pub baseline: Option<String>,
+
+    /// Save the answers as the known answers for the input
+    #[arg(long)]
+    pub accept: bool,
+
+    /// Submit the answers that are not known yet
+    #[arg(long, conflicts_with_all = ["input", "example"])]
+    pub submit: bool,
+
+    /// Read the input from this file instead, or from stdin if it is `-`
+    #[arg(long, value_name = "PATH", conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+
+    /// Run against every `.txt` input in this directory, e.g. the inputs of several accounts
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["input", "example", "submit"])]
+    pub inputs_dir: Option<PathBuf>,
+
+    /// Run an example from the puzzle description instead, starting at 1
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+    pub example: Option<NonZeroUsize>,
+
+    /// The URL of the Advent of Code server
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    pub base_url: String,
+
+    /// Use the session cookie and inputs of another profile than the default one
+    #[arg(long, env = "AOC_PROFILE")]
+    pub profile: Option<String>,
+
+    /// Never ask for input, fail instead (implied if stdin is not a terminal)
+    #[arg(long)]
+    pub no_prompt: bool,
+
+    /// How the results should be printed
+    #[arg(long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+}
+
+impl RunArgs {
+    /// Whether the user may be asked for input.
+    pub fn interactive(&self) -> bool {
+        !self.no_prompt && io::stdin().is_terminal()
+    }
+
+    /// Which parts of the puzzle should be run.
+    ///
+    /// If no part has been selected explicitly, both parts are run.
+    fn parts(&self) -> (bool, bool) {
+        if !self.part_1 && !self.part_2 {
+            (true, true)
+        } else {
+            (self.part_1, self.part_2)
+        }
+    }
+
+    /// How the parts should be benchmarked, if at all.
+    fn bench_options(&self) -> Option<BenchOptions> {
+        self.bench.then_some(BenchOptions {
+            warmup: self.warmup,
+            time: self.bench_time,
+            max_samples: self.samples,
+        })
+    }
+}
+
+/// An object-safe version of [`AocDay`].
+///
+/// [`AocDay`] has an associated const, static functions and generic answer types,
+/// so the solutions for different days can't be stored in one list directly.
+/// Use [`dyn_day`] to wrap a solution into this trait.
+pub trait DynAocDay: Send + Sync {
+    /// The year of the event that the solution is for.
+    fn year(&self) -> u16;
+
+    /// The number of the day that the solution is for.
+    fn day(&self) -> u8;
+
+    /// The path to the input file of the day.
+    fn input_path(&self) -> String;
+
+    /// Whether each part is still unsolved, i.e. its answer is `()`.
+    fn unsolved_parts(&self) -> [bool; 2];
+
+    /// Run the solution with the given options.
+    fn run(&self, args: &RunArgs) -> DayReport;
+}
+
+/// Marks the types of a solution without holding any values of them,
+/// so the wrapper can be shared between threads whatever the types are.
+type SolutionMarker<D, P1, P2> = PhantomData<fn() -> (D, P1, P2)>;
+
+struct DynAocDayWrapper<D, P1, P2> {
+    _marker: SolutionMarker<D, P1, P2>,
+}
+
+impl<D, P1, P2> DynAocDay for DynAocDayWrapper<D, P1, P2>
+where
+    D: AocDay<P1, P2> + 'static,
+    P1: AocAnswer + 'static,
+    P2: AocAnswer + 'static,
+{
+    fn year(&self) -> u16 {
+        D::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        D::DAY
+    }
+
+    fn input_path(&self) -> String {
+        D::get_file_path()
+    }
+
+    fn unsolved_parts(&self) -> [bool; 2] {
+        [TypeId::of::<P1>(), TypeId::of::<P2>()].map(|answer| answer == TypeId::of::<()>())
+    }
+
+    fn run(&self, args: &RunArgs) -> DayReport {
+        run_day::<D, P1, P2>(args)
+    }
+}
+
+/// Wrap the solution of a day into a [`DynAocDay`].
+///
+/// The answer types can usually be inferred, e.g. `dyn_day::<Day01, _, _>()`.
+pub fn dyn_day<D, P1, P2>() -> Box<dyn DynAocDay>
+where
+    D: AocDay<P1, P2> + 'static,
+    P1: AocAnswer + 'static,
+    P2: AocAnswer + 'static,
+{
+    Box::new(DynAocDayWrapper::<D, P1, P2> {
+        _marker: PhantomData,
+    })
+}
+
+/// The results of running the solution of a day.
+#[derive(Debug, Clone, Serialize)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub input_path: String,
+    /// The number of lines of the input, if it could be obtained.
+    pub input_lines: Option<usize>,
+    #[serde(rename = "input_time_ns", serialize_with = "serialize_duration")]
+    pub input_time: Duration,
+    /// How long it took to parse the input for both parts.
+    #[serde(rename = "parse_time_ns", serialize_with = "serialize_duration")]
+    pub parse_time: Duration,
+    pub part_1: Option<RunResult>,
+    pub part_2: Option<RunResult>,
+    /// Why the first part did not produce an answer, e.g. because it panicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_1_failure: Option<PartFailure>,
+    /// Why the second part did not produce an answer, e.g. because it panicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_2_failure: Option<PartFailure>,
+    /// The reason why the solution could not be run.
+    pub error: Option<String>,
+    #[serde(rename = "time_ns", serialize_with = "serialize_duration")]
+    pub time: Duration,
+}
+
+impl Display for DayReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "DAY {:0>2}\n----", self.day)?;
+
+        if let Some(input_lines) = self.input_lines {
+            writeln!(
+                f,
+                "INPUT: {} ({} lines) [{:?}]",
+                self.input_path, input_lines, self.input_time
+            )?;
+            writeln!(f, "PARSE: [{:?}]", self.parse_time)?;
+        } else {
+            writeln!(f, "INPUT: {}", self.input_path)?;
+        }
+
+        for (part, result, failure) in [
+            (1, &self.part_1, &self.part_1_failure),
+            (2, &self.part_2, &self.part_2_failure),
+        ] {
+            if let Some(result) = result {
+                writeln!(f, "PART {part}: {result}")?;
+            } else if let Some(failure) = failure {
+                writeln!(f, "PART {part}: {failure}")?;
+            }
+        }
+
+        if let Some(error) = &self.error {
+            writeln!(f, "ERROR: {error}")?;
+        }
+
+        writeln!(f, "----\nFinished in {:?}", self.time)
+    }
+}
+
+/// Run the solution of a day with the given options.
+pub fn run_day<D, P1, P2>(args: &RunArgs) -> DayReport
+where
+    D: AocDay<P1, P2> + 'static,
+    P1: AocAnswer + 'static,
+    P2: AocAnswer + 'static,
+{
+    let start = Instant::now();
+    let (part_1, part_2) = args.parts();
+
+    // The profile decides where the inputs are, so it has to be selected first
+    let profile_error = args
+        .profile
+        .as_deref()
+        .and_then(|profile| select_profile(profile).err());
+
+    let mut report = DayReport {
+        year: D::YEAR,
+        day: D::DAY,
+        input_path: match (&args.input, args.example) {
+            (Some(path), _) => input_source_name(path),
+            (None, Some(example)) => D::get_example_file_path(example.get()),
+            (None, None) => D::get_file_path(),
+        },
+        input_lines: None,
+        input_time: Duration::ZERO,
+        parse_time: Duration::ZERO,
+        part_1: None,
+        part_2: None,
+        part_1_failure: None,
+        part_2_failure: None,
+        error: None,
+        time: Duration::ZERO,
+    };
+
+    if let Some(err) = profile_error {
+        report.error = Some(err.to_string());
+        return report;
+    }
+
+    if args.mem && !MEASURE_MEMORY {
+        report.error = Some(
+            "Measuring the memory requires the `mem` feature, e.g. `--features aoc_utils/mem`"
+                .to_string(),
+        );
+        return report;
+    }
+
+    let start_input = Instant::now();
+    let input = match (&args.input, args.example) {
+        (Some(path), _) => read_input(path),
+        (None, Some(example)) => D::get_example(&args.base_url, example),
+        (None, None) => D::get_input(&args.base_url, args.interactive()),
+    };
+    report.input_time = start_input.elapsed();
+
+    match input {
+        Ok(input) => {
+            let input = D::INPUT_NORMALIZATION.apply(&input).to_string();
+            report.input_lines = Some(input.lines().count());
+
+            // A failing part is reported, but doesn't prevent running the other part
+            let options = PartOptions {
+                bench: args.bench_options(),
+                mem: args.mem,
+            };
+
+            if let Some(timeout) = args.timeout {
+                // A part that times out keeps running on its worker thread, so the input has to
+                // live until the process exits
+                let input: &'static str = Box::leak(input.into_boxed_str());
+
+                if let Some(parsed) = parse_input::<D, P1, P2>(input, &mut report) {
+                    let parsed = &*Box::leak(Box::new(parsed));
+                    if part_1 {
+                        let result = run_part_with_timeout(D::part_1, parsed, options, timeout);
+                        report.set_part(1, result);
+                    }
+                    if part_2 {
+                        let result = run_part_with_timeout(D::part_2, parsed, options, timeout);
+                        report.set_part(2, result);
+                    }
+                }
+            } else if let Some(parsed) = parse_input::<D, P1, P2>(&input, &mut report) {
+                if part_1 {
+                    report.set_part(1, run_part_inline(D::part_1, &parsed, options));
+                }
+                if part_2 {
+                    report.set_part(2, run_part_inline(D::part_2, &parsed, options));
+                }
+            }
+
+            if report.error.is_some() {
+                report.time = start.elapsed();
+                return report;
+            }
+
+            if let Err(err) = compare_with_baselines(&mut report, args) {
+                report.error = Some(err.to_string());
+            }
+
+            // There's no place to store the known answers for input from stdin
+            let from_stdin = args.input.as_deref() == Some(Path::new(STDIN_INPUT));
+
+            if from_stdin {
+                if args.accept {
+                    report.error = Some("Can't save the answers for input from stdin".to_string());
+                }
+            } else if let Err(err) = check_answers(&mut report, args.accept) {
+                report.error = Some(err.to_string());
+            } else if args.submit {
+                if let Err(err) =
+                    submit_answers(&mut report, &ReqwestClient::new(), &args.base_url, || {
+                        get_session_cookie(args.interactive())
+                    })
+                {
+                    report.error = Some(err.to_string());
+                }
+            }
+        }
+        Err(err) => report.error = Some(err.to_string()),
+    }
+
+    report.time = start.elapsed();
+    report
+}
+
+/// Parse the input, reporting a panic as an error of the day.
+fn parse_input<'a, D, P1, P2>(input: &'a str, report: &mut DayReport) -> Option<D::Parsed<'a>>
+where
+    D: AocDay<P1, P2>,
+    P1: AocAnswer,
+    P2: AocAnswer,
+{
+    let start_parse = Instant::now();
+    let parsed = catch_failure(|| D::parse(black_box(input)));
+    report.parse_time = start_parse.elapsed();
+
+    parsed
+        .map_err(|failure| report.error = Some(format!("Failed to parse the input: {failure}")))
+        .ok()
+}
+
+impl DayReport {
+    /// Record the result of running a part.
+    fn set_part(&mut self, part: u8, result: Result<RunResult, PartFailure>) {
+        let (result_slot, failure_slot) = match part {
+            1 => (&mut self.part_1, &mut self.part_1_failure),
+            _ => (&mut self.part_2, &mut self.part_2_failure),
+        };
+
+        match result {
+            Ok(result) => *result_slot = Some(result),
+            Err(failure) => *failure_slot = Some(failure),
+        }
+    }
+
+    /// Whether the solution could not be run, or a part did not produce an answer.
+    pub fn failed(&self) -> bool {
+        self.error.is_some() || self.part_1_failure.is_some() || self.part_2_failure.is_some()
+    }
+
+    /// Whether the report needs attention, because a part failed or gave a wrong answer.
+    pub fn needs_attention(&self) -> bool {
+        let wrong = [&self.part_1, &self.part_2]
+            .into_iter()
+            .flatten()
+            .any(|result| matches!(result.status, AnswerStatus::Wrong { .. }));
+
+        wrong || self.failed()
+    }
+}
+
+fn input_name(input_path: &Path) -> String {
+    input_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Compare the benchmark results against a baseline and save them as a baseline, as requested.
+///
+/// The comparison happens first, so a run can be compared against the baseline that it replaces.
+fn compare_with_baselines(report: &mut DayReport, args: &RunArgs) -> Result<(), BaselineError> {
+    if let Some(name) = &args.baseline {
+        let baseline = Baseline::load(&Baseline::path(name))?
+            .ok_or_else(|| BaselineError::Missing { name: name.clone() })?;
+
+        let day = baseline.day(report.year, report.day, &report.input_path);
+        if day.is_none() {
+            eprintln!(
+                "Warning: The baseline {name:?} has no results of day {} on {}",
+                report.day, report.input_path
+            );
+        }
+
+        if let Some(day) = day {
+            if day.is_from_other_machine() {
+                eprintln!(
+                    "Warning: The baseline {name:?} of day {} was recorded on another machine, the timings may not be comparable",
+                    report.day
+                );
+            }
+
+            for (part, result) in [(1, &mut report.part_1), (2, &mut report.part_2)] {
+                if let Some(result) = result {
+                    result.comparison = BaselineComparison::new(&day.times(part), &result.times);
+                }
+            }
+        }
+    }
+
+    if let Some(name) = &args.save_baseline {
+        let path = Baseline::path(name);
+        let mut baseline = Baseline::load(&path)?.unwrap_or_default();
+        let mut day = DayBaseline::new(report.input_path.clone());
+
+        for (part, result) in [(1, &report.part_1), (2, &report.part_2)] {
+            if let Some(result) = result {
+                day.set_times(part, &result.times);
+            }
+        }
+
+        baseline.insert_day(report.year, report.day, day);
+        baseline.save(&path)?;
+    }
+
+    Ok(())
+}
+
+/// Compare the answers against the known answers for the input.
+///
+/// If `accept` is set, the answers are saved as the new known answers instead.
+fn check_answers(report: &mut DayReport, accept: bool) -> Result<(), AnswerStoreError> {
+    let input_path = Path::new(&report.input_path);
+    let input_name = input_name(input_path);
+    let store_path = AnswerStore::path_for_input(input_path);
+    let mut store = AnswerStore::load(&store_path)?;
+
+    for (part, result) in [(1, &mut report.part_1), (2, &mut report.part_2)] {
+        let Some(result) = result else {
+            continue;
+        };
+
+        // A part that hasn't been solved yet has nothing to compare
+        let Some(answer) = &result.answer else {
+            continue;
+        };
+
+        if accept {
+            store.accept(&input_name, part, answer);
+        }
+
+        result.status = store.check(&input_name, part, answer);
+    }
+
+    if accept {
+        store.save(&store_path)?;
+    }
+
+    Ok(())
+}
+
+/// Submit the answers that have not been verified yet.
+///
+/// Answers that are ruled out by an earlier wrong guess are never sent again, but the other part
+/// is still submitted. Once the server asks to wait, no more answers are sent.
+fn submit_answers(
+    report: &mut DayReport,
+    client: &dyn HttpClient,
+    base_url: &str,
+    session_cookie: impl FnOnce() -> Result<String, SessionError>,
+) -> Result<(), SubmitError> {
+    let input_path = Path::new(&report.input_path);
+    let input_name = input_name(input_path);
+    let store_path = AnswerStore::path_for_input(input_path);
+    let mut store = AnswerStore::load(&store_path)?;
+
+    let mut pending = Vec::new();
+    let mut skipped = None;
+    for (part, result) in [(1, &report.part_1), (2, &report.part_2)] {
+        let Some(answer) = result
+            .as_ref()
+            .filter(|result| result.status == AnswerStatus::Unknown)
+            .and_then(|result| result.answer.clone())
+        else {
+            continue;
+        };
+
+        match store.ruled_out_by(&input_name, part, &answer) {
+            Some(guess) => {
+                skipped.get_or_insert(SubmitError::AlreadyGuessed {
+                    part,
+                    guess: guess.clone(),
+                });
+            }
+            None => pending.push((part, answer)),
+        }
+    }
+
+    if !pending.is_empty() {
+        let session_cookie = session_cookie()?;
+
+        for (part, answer) in pending {
+            let outcome = submit_answer(
+                client,
+                base_url,
+                report.year,
+                report.day,
+                part,
+                &answer,
+                &session_cookie,
+            )?;
+
+            let result = match part {
+                1 => &mut report.part_1,
+                _ => &mut report.part_2,
+            };
+            let result = result
+                .as_mut()
+                .expect("Only parts with a result are submitted");
+
+            if outcome == SubmitOutcome::Correct {
+                store.accept(&input_name, part, &answer);
+                result.status = AnswerStatus::Verified;
+            } else if let Some(guess) = WrongGuess::new(&answer, outcome) {
+                store.add_wrong_guess(&input_name, part, guess);
+            }
+
+            result.submission = Some(outcome);
+            store.save(&store_path)?;
+
+            // The server rejects all answers until the wait is over
+            if let SubmitOutcome::RateLimited { wait } = outcome {
+                return Err(SubmitError::RateLimited { wait });
+            }
+        }
+    }
+
+    skipped.map_or(Ok(()), Err)
+}
+
+/// The answer to a puzzle part and how long it took to compute it.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunResult {
+    /// The canonical form of the answer, or `None` if the part hasn't been solved yet.
+    pub answer: Option<String>,
+    /// The answer as it is shown to humans, which may span several lines.
+    #[serde(skip)]
+    pub display: String,
+    /// How long the first run took, before any caches were warmed up.
+    #[serde(rename = "cold_time_ns", serialize_with = "serialize_duration")]
+    pub cold_time: Duration,
+    /// The samples measured when benchmarking, after the warmup.
+    #[serde(rename = "times_ns", serialize_with = "serialize_durations")]
+    pub times: Vec<Duration>,
+    /// The statistics of the samples, if the part has been benchmarked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<BenchStats>,
+    /// The memory used by the first run, if it has been measured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemStats>,
+    /// How the samples compare to the baseline, if one has been given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<BaselineComparison>,
+    /// How the answer compares to the known answer for the input.
+    #[serde(flatten)]
+    pub status: AnswerStatus,
+    /// The reply of the server, if the answer has been submitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submission: Option<SubmitOutcome>,
+}
+
+impl RunResult {
+    /// The median time if the part has been benchmarked, otherwise the time of the first run.
+    pub fn time(&self) -> Duration {
+        self.stats
+            .as_ref()
+            .map_or(self.cold_time, |stats| stats.median)
+    }
+}
+
+impl Display for RunResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Answers spanning several lines are printed below the timing
+        let multiline = self.display.contains('\n');
+
+        if !multiline {
+            write!(f, "{} ", self.display)?;
+        }
+
+        if let Some(stats) = &self.stats {
+            write!(
+                f,
+                "[median {:?}, p5-p95 {:?}-{:?}, σ {:?}, {} samples, {} outliers, cold {:?}]",
+                stats.median,
+                stats.p5,
+                stats.p95,
+                stats.std_dev,
+                stats.samples,
+                stats.outliers,
+                self.cold_time
+            )?;
+        } else {
+            write!(f, "[{:?}]", self.cold_time)?;
+        }
+
+        if let Some(memory) = &self.memory {
+            write!(f, " [{memory}]")?;
+        }
+
+        if let Some(comparison) = &self.comparison {
+            write!(f, " [{comparison}]")?;
+        }
+
+        write!(f, " ({})", self.status)?;
+
+        if let Some(submission) = &self.submission {
+            write!(f, " submitted: {submission}")?;
+        }
+
+        if multiline {
+            for line in self.display.lines() {
+                write!(f, "\n    {line}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Run a part of the puzzle on the current thread, turning panics into failures.
+fn run_part_inline<I, T: AocAnswer>(
+    part: fn(&I) -> T,
+    input: &I,
+    options: PartOptions,
+) -> Result<RunResult, PartFailure> {
+    let (cold_sender, cold_receiver) = mpsc::channel();
+    let (samples_sender, samples_receiver) = mpsc::channel();
+    run_part(part, input, &options, &cold_sender, &samples_sender);
+
+    let result = cold_receiver
+        .recv()
+        .expect("The part catches panics and always sends a result")?;
+    add_samples(result, &options, &samples_receiver)
+}
+
+/// Run a part of the puzzle on a worker thread, turning panics into failures.
+///
+/// Its first run has to finish within the timeout, otherwise the worker thread is abandoned.
+/// Benchmarking stops at the end of the warmup and benchmark time by itself, so it isn't timed out.
+fn run_part_with_timeout<I, T>(
+    part: fn(&I) -> T,
+    input: &'static I,
+    options: PartOptions,
+    timeout: Duration,
+) -> Result<RunResult, PartFailure>
+where
+    I: Sync + 'static,
+    T: AocAnswer + 'static,
+{
+    let (cold_sender, cold_receiver) = mpsc::channel();
+    let (samples_sender, samples_receiver) = mpsc::channel();
+    thread::spawn(move || run_part(part, input, &options, &cold_sender, &samples_sender));
+
+    let result = match cold_receiver.recv_timeout(timeout) {
+        Ok(result) => result?,
+        Err(RecvTimeoutError::Timeout) => return Err(PartFailure::TimedOut { after: timeout }),
+        Err(RecvTimeoutError::Disconnected) => {
+            unreachable!("The part catches panics and always sends a result")
+        }
+    };
+    add_samples(result, &options, &samples_receiver)
+}
+
+/// Add the benchmark samples to the result of the first run, if the part is benchmarked.
+fn add_samples(
+    mut result: RunResult,
+    options: &PartOptions,
+    samples_receiver: &Receiver<Result<Vec<Duration>, PartFailure>>,
+) -> Result<RunResult, PartFailure> {
+    if options.bench.is_some() {
+        let times = samples_receiver
+            .recv()
+            .expect("The part sends the samples when benchmarking")?;
+        result.stats = BenchStats::new(&times);
+        result.times = times;
+    }
+
+    Ok(result)
+}
+
+/// How a part of the puzzle is run.
+#[derive(Debug, Clone, Copy)]
+struct PartOptions {
+    bench: Option<BenchOptions>,
+    /// Whether to measure the memory used by the first run.
+    mem: bool,
+}
+
+/// Run the part once and send the result, then benchmark it and send the samples, as requested.
+fn run_part<I, T: AocAnswer>(
+    part: impl Fn(&I) -> T,
+    input: &I,
+    options: &PartOptions,
+    cold_sender: &Sender<Result<RunResult, PartFailure>>,
+    samples_sender: &Sender<Result<Vec<Duration>, PartFailure>>,
+) {
+    let cold_run = || {
+        let start = Instant::now();
+        let res = part(black_box(input));
+        (res, start.elapsed())
+    };
+
+    let cold_result = catch_failure(|| {
+        if options.mem {
+            measure_memory(cold_run)
+        } else {
+            (cold_run(), None)
+        }
+    });
+
+    // The receivers are gone if the part timed out, so nobody is interested in the results
+    let res = match cold_result {
+        Ok(((res, cold_time), memory)) => {
+            let _ = cold_sender.send(Ok(RunResult {
+                answer: res.submission(),
+                display: res.display(),
+                cold_time,
+                stats: None,
+                comparison: None,
+                times: Vec::new(),
+                memory,
+                status: AnswerStatus::default(),
+                submission: None,
+            }));
+            res
+        }
+        Err(failure) => {
+            let _ = cold_sender.send(Err(failure));
+            return;
+        }
+    };
+
+    if let Some(bench) = &options.bench {
+        let times = catch_failure(|| benchmark(bench, &res, || part(black_box(input))));
+        let _ = samples_sender.send(times);
+    }
+}
+
+/// Reports and results for tests, to be adjusted with the struct update syntax.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::*;
+
+    /// The report of a day without any parts, run on its input.
+    pub(crate) fn report(day: u8) -> DayReport {
+        DayReport {
+            year: 2023,
+            day,
+            input_path: format!("inputs/2023/day_{day:0>2}.txt"),
+            input_lines: Some(10),
+            input_time: Duration::ZERO,
+            parse_time: Duration::ZERO,
+            part_1: None,
+            part_2: None,
+            part_1_failure: None,
+            part_2_failure: None,
+            error: None,
+            time: Duration::ZERO,
+        }
+    }
+
+    /// The result of a part whose first run took 2ms, without benchmarking it.
+    pub(crate) fn result(answer: &str, status: AnswerStatus) -> RunResult {
+        RunResult {
+            answer: Some(answer.to_string()),
+            display: answer.to_string(),
+            cold_time: Duration::from_millis(2),
+            times: vec![],
+            stats: None,
+            memory: None,
+            comparison: None,
+            status,
+            submission: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{HttpResponse, NetworkError};
+
+    fn slow_part(millis: &u64) -> u64 {
+        thread::sleep(Duration::from_millis(*millis));
+        *millis
+    }
+
+    #[test]
+    fn test_timeout_applies_to_first_run() {
+        let options = PartOptions {
+            bench: Some(BenchOptions {
+                warmup: Duration::from_millis(10),
+                time: Duration::from_millis(10),
+                max_samples: None,
+            }),
+            mem: false,
+        };
+        let timeout = Duration::from_millis(150);
+
+        // Only the cold run is limited, the warmup run and the sample after it may exceed the timeout
+        let result = run_part_with_timeout(slow_part, &100, options, timeout).unwrap();
+        assert_eq!(result.answer.as_deref(), Some("100"));
+        assert_eq!(result.times.len(), 1);
+
+        assert_eq!(
+            run_part_with_timeout(slow_part, &300, options, timeout).unwrap_err(),
+            PartFailure::TimedOut {
+                after: Duration::from_millis(150)
+            }
+        );
+    }
+
+    /// A server that replies to every submission with the same page and records the forms.
+    struct FakeServer {
+        reply: String,
+        forms: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl HttpClient for FakeServer {
+        fn get(&self, _url: &str, _session_cookie: &str) -> Result<HttpResponse, NetworkError> {
+            unreachable!("Submitting only posts the answers")
+        }
+
+        fn post_form(
+            &self,
+            _url: &str,
+            _session_cookie: &str,
+            form: &[(&str, &str)],
+        ) -> Result<HttpResponse, NetworkError> {
+            let form: Vec<_> = form
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect();
+            self.forms.lock().unwrap().push(form.join("&"));
+            Ok(HttpResponse::new(200, self.reply.clone()))
+        }
+    }
+
+    /// Submit both parts of a day with an input in a fresh directory.
+    fn submit(
+        name: &str,
+        reply: &str,
+        wrong_part_1: Option<&str>,
+    ) -> (DayReport, Vec<String>, Result<(), SubmitError>) {
+        let dir = std::env::temp_dir().join(format!("aoc_submit_{name}_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input_path = dir.join("day_05.txt");
+
+        if let Some(answer) = wrong_part_1 {
+            let mut store = AnswerStore::default();
+            let guess = WrongGuess::new(answer, SubmitOutcome::TooHigh).unwrap();
+            store.add_wrong_guess("day_05.txt", 1, guess);
+            store
+                .save(&AnswerStore::path_for_input(&input_path))
+                .unwrap();
+        }
+
+        let mut report = DayReport {
+            input_path: input_path.display().to_string(),
+            part_1: Some(fixtures::result("35", AnswerStatus::Unknown)),
+            part_2: Some(fixtures::result("46", AnswerStatus::Unknown)),
+            ..fixtures::report(5)
+        };
+        let server = FakeServer {
+            reply: format!("<article><p>{reply}</p></article>"),
+            forms: Default::default(),
+        };
+
+        let result = submit_answers(&mut report, &server, "", || Ok("secret".to_string()));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        (report, server.forms.into_inner().unwrap(), result)
+    }
+
+    #[test]
+    fn test_submit_answers_skips_already_guessed() {
+        let (report, forms, result) = submit("guessed", "That's the right answer!", Some("30"));
+
+        assert_eq!(forms, ["level=2&answer=46"]);
+        assert_eq!(report.part_1.unwrap().submission, None);
+        assert_eq!(report.part_2.unwrap().status, AnswerStatus::Verified);
+        assert!(matches!(
+            result,
+            Err(SubmitError::AlreadyGuessed { part: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_submit_answers_stops_when_rate_limited() {
+        let (report, forms, result) = submit(
+            "rate_limited",
+            "You gave an answer too recently. You have 30s left to wait.",
+            None,
+        );
+        let wait = Duration::from_secs(30);
+
+        assert_eq!(forms, ["level=1&answer=35"]);
+        assert_eq!(
+            report.part_1.unwrap().submission,
+            Some(SubmitOutcome::RateLimited { wait })
+        );
+        assert_eq!(report.part_2.unwrap().submission, None);
+        assert!(matches!(result, Err(SubmitError::RateLimited { wait: w }) if w == wait));
+    }
+}
@@ -0,0 +1,256 @@
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::sync::Once;
+
+use inquire::InquireError;
+use inquire::Text;
+
+use crate::profiles::{active_profile, update_active_profile};
+
+/// The environment variable that can provide the session cookie.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// The file where the session cookie used to be saved, before there were profiles.
+const LEGACY_SESSION_COOKIE_PATH: &str = "inputs/.session_cookie";
+
+/// The reasons why the session cookie could not be obtained.
+#[derive(Debug)]
+pub enum SessionError {
+    /// No session cookie is available and prompts are disabled.
+    Missing,
+
+    /// Asking the user for the session cookie failed.
+    Prompt(InquireError),
+}
+
+impl Display for SessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing => write!(
+                f,
+                "No session cookie provided! Set the {SESSION_ENV_VAR} environment variable or save it with `aoc auth login`."
+            ),
+            Self::Prompt(err) => write!(f, "Failed to ask for the session cookie: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+impl From<InquireError> for SessionError {
+    fn from(value: InquireError) -> Self {
+        Self::Prompt(value)
+    }
+}
+
+/// The session cookie from the environment or the profile in use, if available.
+///
+/// A cookie saved in the old `inputs/.session_cookie` file is moved to the profiles.
+pub fn saved_session_cookie() -> Option<String> {
+    let (session_cookie, expired_profile) = find_session_cookie()?;
+    if let Some(name) = expired_profile {
+        warn_expired(&name);
+    }
+
+    Some(session_cookie)
+}
+
+/// The saved session cookie, with the name of its profile if the server has rejected it.
+fn find_session_cookie() -> Option<(String, Option<String>)> {
+    if let Some(session_cookie) = env::var(SESSION_ENV_VAR)
+        .ok()
+        .filter(|cookie| !cookie.trim().is_empty())
+    {
+        return Some((session_cookie.trim().to_string(), None));
+    }
+
+    match active_profile() {
+        Ok(Some((name, profile))) => {
+            return Some((profile.session_cookie, profile.expired.then_some(name)));
+        }
+        Ok(None) => {}
+        Err(err) => eprintln!("{err}"),
+    }
+
+    migrate_legacy_session_cookie().map(|session_cookie| (session_cookie, None))
+}
+
+fn warn_expired(name: &str) {
+    WARN_EXPIRED.call_once(|| {
+        eprintln!(
+            "Warning: the session cookie of profile {name:?} has expired, renew it with `aoc auth login --profile {name}`"
+        );
+    });
+}
+
+/// Only warn once per run about an expired session cookie.
+static WARN_EXPIRED: Once = Once::new();
+
+fn migrate_legacy_session_cookie() -> Option<String> {
+    let legacy_path = Path::new(LEGACY_SESSION_COOKIE_PATH);
+    let session_cookie = fs::read_to_string(legacy_path).ok()?.trim().to_string();
+
+    let migrate = || -> Result<String, Box<dyn std::error::Error>> {
+        let name = update_active_profile(&session_cookie, |_| {})?;
+        fs::remove_file(legacy_path)?;
+        Ok(name)
+    };
+
+    match migrate() {
+        Ok(name) => eprintln!("Moved {LEGACY_SESSION_COOKIE_PATH} to the profile {name:?}"),
+        Err(err) => eprintln!("Failed to move {LEGACY_SESSION_COOKIE_PATH} to the profiles: {err}"),
+    }
+
+    Some(session_cookie)
+}
+
+/// Ask the user for their session cookie.
+pub fn prompt_session_cookie() -> Result<String, SessionError> {
+    Ok(Text::new("What is your Advent of Code session cookie?")
+        .with_help_message("You can get your session cookie on https://adventofcode.com using your browsers dev tools.")
+        .prompt()?
+        .trim()
+        .to_string())
+}
+
+/// Get the session cookie, asking the user for it if it is not available and `interactive` is set.
+///
+/// An expired cookie is only used when the user can't be asked for a new one.
+/// A cookie entered by the user is saved in the profile in use.
+pub fn get_session_cookie(interactive: bool) -> Result<String, SessionError> {
+    match find_session_cookie() {
+        Some((session_cookie, None)) => return Ok(session_cookie),
+        Some((session_cookie, Some(name))) if !interactive => {
+            warn_expired(&name);
+            return Ok(session_cookie);
+        }
+        Some((_, Some(name))) => {
+            eprintln!("The session cookie of profile {name:?} has expired, please enter a new one");
+        }
+        None if !interactive => return Err(SessionError::Missing),
+        None => {}
+    }
+
+    let session_cookie = prompt_session_cookie()?;
+
+    if let Err(err) = update_active_profile(&session_cookie, |profile| {
+        profile.session_cookie = session_cookie.clone();
+        profile.expired = false;
+    }) {
+        eprintln!("Failed to save the session cookie: {err}");
+    }
+
+    Ok(session_cookie)
+}
+
+/// Remember that the server rejected the session cookie of the profile in use.
+///
+/// Nothing is recorded for a cookie from the environment.
+pub fn mark_session_expired() {
+    if env::var(SESSION_ENV_VAR).is_ok_and(|cookie| !cookie.trim().is_empty()) {
+        return;
+    }
+
+    if let Ok(Some(_)) = active_profile() {
+        if let Err(err) = update_active_profile("", |profile| profile.expired = true) {
+            eprintln!("Failed to mark the session cookie as expired: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::profiles::{forget_loaded_store, Profile, ProfileStore, CONFIG_DIR_ENV_VAR};
+    use crate::{AocDay, InputError};
+
+    /// The environment is shared by all tests, so only one test may change it at a time.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    struct MissingInput;
+
+    impl AocDay<u32, u32> for MissingInput {
+        const DAY: u8 = 25;
+
+        type Parsed<'a> = &'a str;
+
+        fn parse(input: &str) -> &str {
+            input
+        }
+
+        fn part_1(_input: &&str) -> u32 {
+            0
+        }
+
+        fn part_2(_input: &&str) -> u32 {
+            0
+        }
+    }
+
+    /// Run the test with an empty configuration directory and the session cookie from the environment, if any.
+    fn with_config_dir(name: &str, session_cookie: Option<&str>, test: impl FnOnce()) {
+        let _lock = ENV_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let dir = env::temp_dir().join(format!("aoc_session_{name}_{}", std::process::id()));
+
+        env::set_var(CONFIG_DIR_ENV_VAR, &dir);
+        match session_cookie {
+            Some(session_cookie) => env::set_var(SESSION_ENV_VAR, session_cookie),
+            None => env::remove_var(SESSION_ENV_VAR),
+        }
+        forget_loaded_store();
+
+        test();
+
+        env::remove_var(CONFIG_DIR_ENV_VAR);
+        env::remove_var(SESSION_ENV_VAR);
+        forget_loaded_store();
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_no_session_cookie_without_prompt() {
+        with_config_dir("missing", None, || {
+            assert!(matches!(
+                get_session_cookie(false),
+                Err(SessionError::Missing)
+            ));
+            assert!(matches!(
+                MissingInput::get_input("http://127.0.0.1:9", false),
+                Err(InputError::PromptDisabled { .. })
+            ));
+            assert!(!ProfileStore::default_path().unwrap().exists());
+        });
+    }
+
+    #[test]
+    fn test_session_cookie_from_env() {
+        with_config_dir("env", Some(" abc\n"), || {
+            // Prompting would fail in the tests, so the cookie must be used without asking
+            assert_eq!(get_session_cookie(true).unwrap(), "abc");
+            assert_eq!(get_session_cookie(false).unwrap(), "abc");
+            assert!(!ProfileStore::default_path().unwrap().exists());
+        });
+    }
+
+    #[test]
+    fn test_expired_session_cookie_without_prompt() {
+        with_config_dir("expired", None, || {
+            let mut store = ProfileStore::default();
+            store.insert(
+                "alice",
+                Profile {
+                    expired: true,
+                    ..Profile::new("alice", "abc")
+                },
+            );
+            store.save(&ProfileStore::default_path().unwrap()).unwrap();
+
+            assert_eq!(get_session_cookie(false).unwrap(), "abc");
+            assert_eq!(saved_session_cookie().as_deref(), Some("abc"));
+        });
+    }
+}
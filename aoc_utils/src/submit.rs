@@ -0,0 +1,347 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::http::{HttpClient, NetworkError};
+use crate::output::serialize_duration;
+use crate::{format_countdown, AnswerStoreError, SessionError};
+
+/// The reply of the server after submitting an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum SubmitOutcome {
+    /// The answer is correct.
+    Correct,
+
+    /// The answer is wrong, it is too high.
+    TooHigh,
+
+    /// The answer is wrong, it is too low.
+    TooLow,
+
+    /// The answer is wrong.
+    Wrong,
+
+    /// An answer has been submitted too recently, try again after the given time.
+    RateLimited {
+        #[serde(rename = "wait_ns", serialize_with = "serialize_duration")]
+        wait: Duration,
+    },
+
+    /// The puzzle part has already been solved.
+    AlreadySolved,
+}
+
+impl SubmitOutcome {
+    /// Parse the HTML page that the server replies with after submitting an answer.
+    pub fn parse(html: &str) -> Option<Self> {
+        // Only look at the main content, the rest of the page is the same for all replies
+        let article = html
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(html, |(article, _)| article);
+
+        if article.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if article.contains("You don't seem to be solving the right level") {
+            Some(Self::AlreadySolved)
+        } else if article.contains("You gave an answer too recently") {
+            Some(Self::RateLimited {
+                wait: parse_wait_time(article).unwrap_or_default(),
+            })
+        } else if article.contains("That's not the right answer") {
+            if article.contains("your answer is too high") {
+                Some(Self::TooHigh)
+            } else if article.contains("your answer is too low") {
+                Some(Self::TooLow)
+            } else {
+                Some(Self::Wrong)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited { wait } => write!(f, "rate limited, wait {wait:?}"),
+            Self::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Parse the wait time from e.g. "You have 4m 32s left to wait."
+fn parse_wait_time(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+
+    time.split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|ch: char| !ch.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+
+            match unit {
+                "h" => Some(value * 60 * 60),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// An answer that has been rejected by the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WrongGuess {
+    pub answer: String,
+    pub verdict: GuessVerdict,
+}
+
+/// Why a guess has been rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuessVerdict {
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl WrongGuess {
+    pub fn new(answer: &str, outcome: SubmitOutcome) -> Option<Self> {
+        let verdict = match outcome {
+            SubmitOutcome::TooHigh => GuessVerdict::TooHigh,
+            SubmitOutcome::TooLow => GuessVerdict::TooLow,
+            SubmitOutcome::Wrong => GuessVerdict::Wrong,
+            _ => return None,
+        };
+
+        Some(Self {
+            answer: answer.to_string(),
+            verdict,
+        })
+    }
+
+    /// Whether this guess proves that the given answer is wrong as well.
+    ///
+    /// Besides the same answer, this also rules out numbers beyond a guess that was too high or too low.
+    pub fn rules_out(&self, answer: &str) -> bool {
+        if self.answer == answer {
+            return true;
+        }
+
+        let (Ok(guess), Ok(answer)) = (self.answer.parse::<i128>(), answer.parse::<i128>()) else {
+            return false;
+        };
+
+        match self.verdict {
+            GuessVerdict::TooHigh => answer >= guess,
+            GuessVerdict::TooLow => answer <= guess,
+            GuessVerdict::Wrong => false,
+        }
+    }
+}
+
+/// The reasons why an answer could not be submitted.
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The answer has already been ruled out by an earlier guess.
+    AlreadyGuessed { part: u8, guess: WrongGuess },
+
+    /// An answer has been submitted too recently, so the remaining ones were not sent.
+    RateLimited { wait: Duration },
+
+    /// The session cookie could not be obtained.
+    Session(SessionError),
+
+    /// The request to the server failed.
+    Network(NetworkError),
+
+    /// The server replied with an error status.
+    UnexpectedStatus(u16),
+
+    /// The server replied with a page that could not be understood.
+    UnexpectedResponse(String),
+
+    /// The guess could not be saved.
+    Store(AnswerStoreError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyGuessed { part, guess } => write!(
+                f,
+                "Not submitting part {part}, the answer {} was already {}",
+                guess.answer,
+                match guess.verdict {
+                    GuessVerdict::TooHigh => "too high",
+                    GuessVerdict::TooLow => "too low",
+                    GuessVerdict::Wrong => "wrong",
+                }
+            ),
+            Self::RateLimited { wait } => write!(
+                f,
+                "Stopped submitting, the server accepts answers again in {}",
+                format_countdown(*wait)
+            ),
+            Self::Session(err) => write!(f, "{err}"),
+            Self::Network(err) => write!(f, "Failed to submit the answer: {err}"),
+            Self::UnexpectedStatus(status) => {
+                write!(f, "The server replied with unexpected status {status}")
+            }
+            Self::UnexpectedResponse(_) => {
+                write!(f, "Failed to understand the reply to the submission")
+            }
+            Self::Store(err) => write!(f, "Failed to save the submission: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<SessionError> for SubmitError {
+    fn from(value: SessionError) -> Self {
+        Self::Session(value)
+    }
+}
+
+impl From<NetworkError> for SubmitError {
+    fn from(value: NetworkError) -> Self {
+        Self::Network(value)
+    }
+}
+
+impl From<AnswerStoreError> for SubmitError {
+    fn from(value: AnswerStoreError) -> Self {
+        Self::Store(value)
+    }
+}
+
+/// Submit the answer to a part of the puzzle.
+pub fn submit_answer(
+    client: &dyn HttpClient,
+    base_url: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    session_cookie: &str,
+) -> Result<SubmitOutcome, SubmitError> {
+    if session_cookie.trim().is_empty() {
+        return Err(SubmitError::Session(SessionError::Missing));
+    }
+
+    let response = client.post_form(
+        &format!("{base_url}/{year}/day/{day}/answer"),
+        session_cookie,
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+
+    if response.status != 200 {
+        return Err(SubmitError::UnexpectedStatus(response.status));
+    }
+
+    SubmitOutcome::parse(&response.body).ok_or(SubmitError::UnexpectedResponse(response.body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::test_server::serve_once;
+    use crate::http::ReqwestClient;
+
+    fn reply(text: &str) -> String {
+        format!("<html><body><main><article><p>{text}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            SubmitOutcome::parse(&reply("That's the right answer! You are one gold star closer to restoring snow operations.")),
+            Some(SubmitOutcome::Correct)
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&reply("That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>. Please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a>")),
+            Some(SubmitOutcome::TooHigh)
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&reply(
+                "That's not the right answer; your answer is too low."
+            )),
+            Some(SubmitOutcome::TooLow)
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&reply("That's not the right answer. If you're stuck, make sure you're using the full input data.")),
+            Some(SubmitOutcome::Wrong)
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&reply("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a>")),
+            Some(SubmitOutcome::RateLimited {
+                wait: Duration::from_secs(4 * 60 + 32)
+            })
+        );
+        assert_eq!(
+            SubmitOutcome::parse(&reply("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/1\">[Return to Day 1]</a>")),
+            Some(SubmitOutcome::AlreadySolved)
+        );
+        assert_eq!(SubmitOutcome::parse(&reply("Something else")), None);
+    }
+
+    #[test]
+    fn test_wrong_guess_rules_out() {
+        let too_high = WrongGuess::new("100", SubmitOutcome::TooHigh).unwrap();
+        assert!(too_high.rules_out("100"));
+        assert!(too_high.rules_out("150"));
+        assert!(!too_high.rules_out("99"));
+
+        let too_low = WrongGuess::new("100", SubmitOutcome::TooLow).unwrap();
+        assert!(too_low.rules_out("50"));
+        assert!(!too_low.rules_out("101"));
+
+        let wrong = WrongGuess::new("abc", SubmitOutcome::Wrong).unwrap();
+        assert!(wrong.rules_out("abc"));
+        assert!(!wrong.rules_out("abd"));
+
+        assert_eq!(WrongGuess::new("100", SubmitOutcome::Correct), None);
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = serve_once(200, &reply("That's the right answer!"));
+
+        let outcome =
+            submit_answer(&ReqwestClient::new(), &base_url, 2023, 5, 2, "46", "secret").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(outcome, SubmitOutcome::Correct);
+        assert_eq!(request.request_line, "POST /2023/day/5/answer HTTP/1.1");
+        assert_eq!(request.cookie, "session=secret");
+        assert_eq!(request.body, "level=2&answer=46");
+    }
+
+    #[test]
+    fn test_submit_answer_without_cookie() {
+        let outcome = submit_answer(
+            &ReqwestClient::new(),
+            "http://localhost:1",
+            2023,
+            5,
+            2,
+            "46",
+            "",
+        );
+
+        assert!(matches!(
+            outcome,
+            Err(SubmitError::Session(SessionError::Missing))
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+
+use crate::answers::AnswerStatus;
+use crate::failure::PartFailure;
+use crate::runner::{DayReport, RunResult};
+use crate::table::{answer_cell, Table};
+
+/// How the rows of the summary table are ordered.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// By day and part
+    #[default]
+    Day,
+    /// The slowest parts first
+    Time,
+}
+
+/// A part of a day in the summary table.
+struct SummaryRow<'a> {
+    report: &'a DayReport,
+    part: Option<u8>,
+    result: Option<&'a RunResult>,
+    failure: Option<&'a PartFailure>,
+}
+
+impl SummaryRow<'_> {
+    /// The median time if the part has been benchmarked, otherwise the time of the first run.
+    fn time(&self) -> Option<Duration> {
+        self.result.map(RunResult::time)
+    }
+
+    /// Whether the part failed or gave a wrong answer, or the whole day failed.
+    fn needs_attention(&self) -> bool {
+        let wrong = self
+            .result
+            .is_some_and(|result| matches!(result.status, AnswerStatus::Wrong { .. }));
+
+        wrong || self.failure.is_some() || self.part.is_none()
+    }
+}
+
+/// The total time of the solutions: parsing the inputs and running the parts.
+///
+/// Reading the inputs is not included, as it depends on the disk or the network.
+pub fn total_time(reports: &[DayReport]) -> Duration {
+    reports
+        .iter()
+        .map(|report| {
+            let parts: Duration = [&report.part_1, &report.part_2]
+                .into_iter()
+                .flatten()
+                .map(RunResult::time)
+                .sum();
+            report.parse_time + parts
+        })
+        .sum()
+}
+
+/// Summarize the reports of several days in a table with one row per part.
+///
+/// Each part shows its answer, time and share of the total time, so slow days are easy to spot.
+/// If a budget is given, the total time is compared against it.
+pub fn summary_table(reports: &[DayReport], sort: SortOrder, budget: Option<Duration>) -> String {
+    let mut rows = Vec::new();
+    for report in reports {
+        if report.error.is_some() {
+            rows.push(SummaryRow {
+                report,
+                part: None,
+                result: None,
+                failure: None,
+            });
+            continue;
+        }
+
+        for (part, result, failure) in [
+            (1, &report.part_1, &report.part_1_failure),
+            (2, &report.part_2, &report.part_2_failure),
+        ] {
+            if result.is_some() || failure.is_some() {
+                rows.push(SummaryRow {
+                    report,
+                    part: Some(part),
+                    result: result.as_ref(),
+                    failure: failure.as_ref(),
+                });
+            }
+        }
+    }
+
+    if sort == SortOrder::Time {
+        // Parts without a time go last
+        rows.sort_by_key(|row| std::cmp::Reverse(row.time()));
+    }
+
+    let total = total_time(reports);
+    // The times and shares are aligned to the right, so their magnitudes can be compared
+    let mut table =
+        Table::new(["DAY", "PART", "ANSWER", "TIME", "SHARE", "STATUS"]).align_right([3, 4]);
+    for row in &rows {
+        let time = row.time();
+        let share = time
+            .filter(|_| !total.is_zero())
+            .map(|time| format!("{:.1}%", 100.0 * time.as_secs_f64() / total.as_secs_f64()));
+        let status = match (row.result, row.failure) {
+            (Some(result), _) => result.status.to_string(),
+            (None, Some(failure)) => failure.to_string(),
+            (None, None) => row.report.error.clone().unwrap_or_default(),
+        };
+
+        table.push(
+            row.needs_attention(),
+            [
+                format!("{:0>2}", row.report.day),
+                row.part.map(|part| part.to_string()).unwrap_or_default(),
+                row.result.map(answer_cell).unwrap_or_default(),
+                time.map(|time| format!("{time:.1?}")).unwrap_or_default(),
+                share.unwrap_or_default(),
+                status,
+            ],
+        );
+    }
+    let mut table = table.render();
+
+    let flagged = reports
+        .iter()
+        .filter(|report| report.needs_attention())
+        .count();
+    let parse_time: Duration = reports.iter().map(|report| report.parse_time).sum();
+    table += &format!(
+        "----\n{} days, {flagged} need attention\nTotal {total:.1?} (parsing {parse_time:.1?})",
+        reports.len()
+    );
+
+    match budget {
+        Some(budget) if total <= budget => table += &format!(", within the budget of {budget:?}"),
+        Some(budget) => table += &format!(", OVER the budget of {budget:?}"),
+        None => {}
+    }
+    table += "\n";
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::fixtures;
+
+    fn report(
+        day: u8,
+        part_1: Option<RunResult>,
+        part_2_failure: Option<PartFailure>,
+    ) -> DayReport {
+        DayReport {
+            input_time: Duration::from_millis(100),
+            parse_time: Duration::from_millis(1),
+            part_1,
+            part_2_failure,
+            ..fixtures::report(day)
+        }
+    }
+
+    fn result(answer: &str, millis: u64, status: AnswerStatus) -> RunResult {
+        RunResult {
+            cold_time: Duration::from_millis(millis),
+            ..fixtures::result(answer, status)
+        }
+    }
+
+    fn reports() -> Vec<DayReport> {
+        let mut failed = report(3, None, None);
+        failed.error = Some("Input file inputs/2023/day_03.txt is missing".to_string());
+
+        vec![
+            report(1, Some(result("142", 2, AnswerStatus::Verified)), None),
+            report(
+                2,
+                Some(result("8", 5, AnswerStatus::Unknown)),
+                Some(PartFailure::NotImplemented),
+            ),
+            failed,
+        ]
+    }
+
+    #[test]
+    fn test_summary_table() {
+        assert_eq!(
+            summary_table(&reports(), SortOrder::Day, Some(Duration::from_secs(1))),
+            "    DAY  PART  ANSWER   TIME  SHARE  STATUS
+    01   1     142     2.0ms  20.0%  verified
+    02   1     8       5.0ms  50.0%  unknown
+!!  02   2                           not implemented
+!!  03                               Input file inputs/2023/day_03.txt is missing
+----
+3 days, 2 need attention
+Total 10.0ms (parsing 3.0ms), within the budget of 1s
+"
+        );
+    }
+
+    #[test]
+    fn test_summary_table_by_time() {
+        let table = summary_table(&reports(), SortOrder::Time, Some(Duration::from_millis(5)));
+        let days: Vec<_> = table
+            .lines()
+            .skip(1)
+            .take(4)
+            .map(|line| &line[4..6])
+            .collect();
+
+        assert_eq!(days, ["02", "01", "02", "03"]);
+        assert!(table.ends_with("Total 10.0ms (parsing 3.0ms), OVER the budget of 5ms\n"));
+    }
+}
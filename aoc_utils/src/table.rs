@@ -0,0 +1,107 @@
+use crate::runner::RunResult;
+
+/// A plain text table with aligned columns, e.g. to compare several days or inputs.
+///
+/// Each row starts with a flag column, which marks the rows that need attention with `!!`.
+pub(crate) struct Table<const N: usize> {
+    header: [String; N],
+    rows: Vec<(bool, [String; N])>,
+    right_aligned: Vec<usize>,
+    hide_empty_columns: bool,
+}
+
+impl<const N: usize> Table<N> {
+    pub(crate) fn new(header: [&str; N]) -> Self {
+        Self {
+            header: header.map(String::from),
+            rows: Vec::new(),
+            right_aligned: Vec::new(),
+            hide_empty_columns: false,
+        }
+    }
+
+    /// Align the cells of these columns to the right, so the magnitudes of numbers can be compared.
+    pub(crate) fn align_right(mut self, columns: impl IntoIterator<Item = usize>) -> Self {
+        self.right_aligned.extend(columns);
+        self
+    }
+
+    /// Leave out the columns that are empty in all rows, e.g. a part that wasn't run.
+    pub(crate) fn hide_empty_columns(mut self) -> Self {
+        self.hide_empty_columns = true;
+        self
+    }
+
+    pub(crate) fn push(&mut self, flagged: bool, row: [String; N]) {
+        self.rows.push((flagged, row));
+    }
+
+    /// The table with one line per row, starting with the header.
+    pub(crate) fn render(&self) -> String {
+        let mut widths = [0; N];
+        for row in std::iter::once(&self.header).chain(self.rows.iter().map(|(_, row)| row)) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let shown: Vec<_> = (0..N)
+            .filter(|&column| {
+                !self.hide_empty_columns || self.rows.iter().any(|(_, row)| !row[column].is_empty())
+            })
+            .collect();
+
+        let rows = std::iter::once((false, &self.header))
+            .chain(self.rows.iter().map(|(flagged, row)| (*flagged, row)));
+
+        let mut table = String::new();
+        for (flagged, row) in rows {
+            // The flag column is always shown, so the rows stay aligned
+            let mut line = vec![if flagged { "!!" } else { "  " }.to_string()];
+            line.extend(shown.iter().map(|&column| {
+                let (cell, width) = (&row[column], widths[column]);
+                if self.right_aligned.contains(&column) {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            }));
+
+            table += line.join("  ").trim_end();
+            table += "\n";
+        }
+
+        table
+    }
+}
+
+/// The answer of a part as shown in a table cell, with multi-line answers abbreviated.
+pub(crate) fn answer_cell(result: &RunResult) -> String {
+    if result.display.contains('\n') {
+        format!("<{} lines>", result.display.lines().count())
+    } else {
+        result.display.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let mut table = Table::new(["NAME", "EMPTY", "COUNT"])
+            .align_right([2])
+            .hide_empty_columns();
+        table.push(false, ["a".to_string(), String::new(), "7".to_string()]);
+        table.push(true, ["bcd".to_string(), String::new(), "12".to_string()]);
+
+        assert_eq!(
+            table.render(),
+            "    NAME  COUNT
+    a         7
+!!  bcd      12
+"
+        );
+    }
+}
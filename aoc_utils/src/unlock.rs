@@ -0,0 +1,231 @@
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::clock::Clock;
+use crate::download::{download_and_save_input, DownloadError};
+use crate::http::HttpClient;
+
+/// The puzzles unlock at midnight US Eastern time, which is UTC-5 in December.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// How often the countdown is updated while waiting.
+const COUNTDOWN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long to wait before asking again if the input is not available right after the unlock,
+/// e.g. because the local clock is ahead of the server.
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// How often to ask again for an input that is not available yet.
+const MAX_RETRIES: usize = 12;
+
+/// The time at which the puzzle of the day unlocks.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_since_epoch(year.into(), 12, day.into());
+
+    UNIX_EPOCH + Duration::from_secs(days * 24 * 60 * 60 + UNLOCK_HOUR_UTC * 60 * 60)
+}
+
+/// The time left until the puzzle unlocks, or `None` if it is already available.
+pub fn time_until_unlock(clock: &dyn Clock, year: u16, day: u8) -> Option<Duration> {
+    unlock_time(year, day)
+        .duration_since(clock.now())
+        .ok()
+        .filter(|left| !left.is_zero())
+}
+
+/// The number of days from 1970-01-01 to the given date, which must not be before it.
+fn days_since_epoch(year: u64, month: u64, day: u64) -> u64 {
+    // Count the years from March, so that the leap day is at the end of the year
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+
+    let days_before_year = 365 * year + year / 4 - year / 100 + year / 400;
+    let days_before_month = (153 * month + 2) / 5;
+
+    // 719_468 is the number of days from 0000-03-01 to 1970-01-01
+    days_before_year + days_before_month + day - 1 - 719_468
+}
+
+/// Wait until the puzzle unlocks, calling `on_tick` with the time left every second.
+pub fn wait_for_unlock(clock: &dyn Clock, year: u16, day: u8, mut on_tick: impl FnMut(Duration)) {
+    while let Some(left) = time_until_unlock(clock, year, day) {
+        on_tick(left);
+
+        // Sleep until the next full second of the countdown
+        let step = match left.subsec_nanos() {
+            0 => COUNTDOWN_INTERVAL,
+            nanos => Duration::from_nanos(nanos.into()),
+        };
+        clock.sleep(step.min(left));
+    }
+}
+
+/// Download the input of a puzzle that has just unlocked and save it.
+///
+/// If the server doesn't have the input yet, it is asked again a few times.
+pub fn download_with_retries(
+    clock: &dyn Clock,
+    client: &dyn HttpClient,
+    base_url: &str,
+    year: u16,
+    day: u8,
+    session_cookie: &str,
+    file_path: &Path,
+) -> Result<String, DownloadError> {
+    let mut retries = 0;
+    loop {
+        match download_and_save_input(client, base_url, year, day, session_cookie, file_path) {
+            Err(DownloadError::NotFound) if retries < MAX_RETRIES => {
+                retries += 1;
+                clock.sleep(RETRY_DELAY);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Format the time left as e.g. `2d 03:04:05`.
+pub fn format_countdown(left: Duration) -> String {
+    // Round up, so that the countdown reaches zero at the unlock
+    let secs = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let time = format!(
+        "{:0>2}:{:0>2}:{:0>2}",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::clock::fake::FakeClock;
+    use crate::http::{HttpResponse, NetworkError};
+
+    /// 2023-12-01T05:00:00Z
+    const DAY_1_UNLOCK: u64 = 1_701_406_800;
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(
+            unlock_time(2023, 1),
+            UNIX_EPOCH + Duration::from_secs(DAY_1_UNLOCK)
+        );
+        assert_eq!(
+            unlock_time(2023, 25),
+            UNIX_EPOCH + Duration::from_secs(DAY_1_UNLOCK + 24 * 86_400)
+        );
+        // 2024-12-01T05:00:00Z, after the leap day
+        assert_eq!(
+            unlock_time(2024, 1),
+            UNIX_EPOCH + Duration::from_secs(1_733_029_200)
+        );
+
+        let clock = FakeClock::at(DAY_1_UNLOCK - 90);
+        assert_eq!(
+            time_until_unlock(&clock, 2023, 1),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(
+            time_until_unlock(&FakeClock::at(DAY_1_UNLOCK), 2023, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_wait_for_unlock() {
+        let clock = FakeClock::at(DAY_1_UNLOCK - 3);
+        let mut countdown = Vec::new();
+
+        wait_for_unlock(&clock, 2023, 1, |left| {
+            countdown.push(format_countdown(left))
+        });
+
+        assert_eq!(countdown, ["00:00:03", "00:00:02", "00:00:01"]);
+        assert_eq!(clock.now(), unlock_time(2023, 1));
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_millis(59_500)), "00:01:00");
+        assert_eq!(
+            format_countdown(Duration::from_secs(2 * 86_400 + 3 * 3600 + 4 * 60 + 5)),
+            "2d 03:04:05"
+        );
+    }
+
+    /// A server that doesn't have the input for the first requests.
+    struct LateServer {
+        requests: Cell<usize>,
+        not_found: usize,
+    }
+
+    impl HttpClient for LateServer {
+        fn get(&self, _url: &str, _session_cookie: &str) -> Result<HttpResponse, NetworkError> {
+            self.requests.set(self.requests.get() + 1);
+
+            if self.requests.get() <= self.not_found {
+                Ok(HttpResponse::new(
+                    404,
+                    "Please don't repeatedly request this endpoint before it unlocks!",
+                ))
+            } else {
+                Ok(HttpResponse::new(200, "1abc2\n"))
+            }
+        }
+
+        fn post_form(
+            &self,
+            _url: &str,
+            _session_cookie: &str,
+            _form: &[(&str, &str)],
+        ) -> Result<HttpResponse, NetworkError> {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_download_with_retries() {
+        let dir = std::env::temp_dir().join(format!("aoc_unlock_{}", std::process::id()));
+        let file_path = dir.join("day_01.txt");
+        let clock = FakeClock::at(DAY_1_UNLOCK - 10);
+        let server = LateServer {
+            requests: Cell::new(0),
+            not_found: 2,
+        };
+
+        wait_for_unlock(&clock, 2023, 1, |_| {});
+        let input = download_with_retries(
+            &clock,
+            &server,
+            "https://adventofcode.com",
+            2023,
+            1,
+            "secret",
+            &file_path,
+        );
+        let saved = std::fs::read_to_string(&file_path);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(input.unwrap(), "1abc2\n");
+        assert_eq!(saved.unwrap(), "1abc2\n");
+        assert_eq!(server.requests.get(), 3);
+        assert_eq!(
+            clock.now(),
+            unlock_time(2023, 1) + RETRY_DELAY * 2,
+            "Expected to wait for the unlock and two retries"
+        );
+    }
+}
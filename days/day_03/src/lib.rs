@@ -0,0 +1,45 @@
+use aoc_utils::{AocDay, InputNormalization};
+use parser::parse_full_schematic;
+use types::Schematic;
+
+mod parser;
+mod types;
+
+pub struct Day03;
+
+impl AocDay<u32, u32> for Day03 {
+    const DAY: u8 = 3;
+    const INPUT_NORMALIZATION: InputNormalization = InputNormalization::Trim;
+
+    type Parsed<'a> = Schematic;
+
+    fn parse(input: &str) -> Schematic {
+        parse_full_schematic(input)
+    }
+
+    fn part_1(input: &Schematic) -> u32 {
+        input.part_numbers().iter().map(|num| num.value).sum()
+    }
+
+    fn part_2(input: &Schematic) -> u32 {
+        input.gear_ratios()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_utils::examples!(Day03 {
+        example: "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.." => { part_1: 4361, part_2: 467835 },
+    });
+}
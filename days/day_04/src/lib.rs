@@ -0,0 +1,87 @@
+use aoc_utils::{InputNormalization, RawAocDay};
+
+pub struct Day04;
+
+impl RawAocDay<u32, u32> for Day04 {
+    const DAY: u8 = 4;
+    const INPUT_NORMALIZATION: InputNormalization = InputNormalization::Trim;
+
+    fn part_1(input: &str) -> u32 {
+        input
+            .lines()
+            .map(|line| {
+                let (_, second) = line.split_once(':').expect("No : contained in line");
+                let (winning, draw) = second.split_once('|').expect("No | contained in line");
+                let winning_numbers = parse_number_list(winning);
+                let drawn_numbers = parse_number_list(draw);
+                let drawn_winning = drawn_numbers
+                    .iter()
+                    .filter(|num| winning_numbers.contains(num))
+                    .count();
+
+                let mut score = 0;
+                for _ in 0..drawn_winning {
+                    score = match score {
+                        0 => 1,
+                        _ => score * 2,
+                    };
+                }
+                score
+            })
+            .sum()
+    }
+
+    fn part_2(input: &str) -> u32 {
+        let winning_draws: Vec<_> = input
+            .lines()
+            .map(|line| {
+                let (_, second) = line.split_once(':').expect("No : contained in line");
+                let (winning, draw) = second.split_once('|').expect("No | contained in line");
+                let winning_numbers = parse_number_list(winning);
+                let drawn_numbers = parse_number_list(draw);
+
+                drawn_numbers
+                    .iter()
+                    .filter(|num| winning_numbers.contains(num))
+                    .count()
+            })
+            .collect();
+
+        let mut copies: Vec<_> = winning_draws.iter().map(|_| 1).collect();
+
+        for (i, winning) in winning_draws.iter().enumerate() {
+            let card_copies = copies[i];
+
+            for j in 0..*winning {
+                let index = i + j + 1;
+
+                if index < copies.len() {
+                    copies[index] += card_copies;
+                }
+            }
+        }
+
+        copies.iter().sum()
+    }
+}
+
+fn parse_number_list(input: &str) -> Vec<u32> {
+    input
+        .split(' ')
+        .filter_map(|segment| segment.parse::<u32>().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_utils::examples!(Day04 {
+        example: "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11" => { part_1: 13, part_2: 30 },
+    });
+}
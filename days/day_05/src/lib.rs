@@ -0,0 +1,67 @@
+use aoc_utils::AocDay;
+use parser::parse_full_almanac;
+use types::Almanac;
+
+mod parser;
+mod types;
+
+pub struct Day05;
+
+impl AocDay<u32, u32> for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = Almanac;
+
+    fn parse(input: &str) -> Almanac {
+        parse_full_almanac(input)
+    }
+
+    fn part_1(input: &Almanac) -> u32 {
+        input.lowest_seed_location()
+    }
+
+    fn part_2(input: &Almanac) -> u32 {
+        input.lowest_seed_range_location()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_utils::examples!(Day05 {
+        example: "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4" => { part_1: 35, part_2: 46 },
+    });
+}
@@ -1,8 +1,8 @@
 use nom::{
     bytes::complete::{tag, take_until, take_while1},
-    combinator::{map, map_res},
+    combinator::map_res,
     multi::separated_list0,
-    sequence::{preceded, separated_pair, terminated},
+    sequence::{preceded, terminated},
     IResult, Parser,
 };
 
@@ -15,24 +15,8 @@ fn parse_num(input: &str) -> IResult<&str, u32> {
     .parse(input)
 }
 
-fn parse_value_range(input: &str) -> IResult<&str, ValueRange> {
-    let (input, (start, range)) = separated_pair(parse_num, tag(" "), parse_num)(input)?;
-
-    Ok((input, ValueRange::new(start, range)))
-}
-
-fn parse_seeds(treat_as_range: bool) -> impl Fn(&str) -> IResult<&str, Vec<ValueRange>> {
-    move |input: &str| {
-        if treat_as_range {
-            preceded(tag("seeds: "), separated_list0(tag(" "), parse_value_range)).parse(input)
-        } else {
-            preceded(
-                tag("seeds: "),
-                separated_list0(tag(" "), map(parse_num, ValueRange::single)),
-            )
-            .parse(input)
-        }
-    }
+fn parse_seeds(input: &str) -> IResult<&str, Vec<u32>> {
+    preceded(tag("seeds: "), separated_list0(tag(" "), parse_num)).parse(input)
 }
 
 fn parse_map_entry(input: &str) -> IResult<&str, AlmanacMapEntry> {
@@ -54,23 +38,18 @@ fn parse_map(input: &str) -> IResult<&str, AlmanacMap> {
     Ok((input, AlmanacMap::new(entries)))
 }
 
-fn parse_almanac(treat_as_range: bool) -> impl Fn(&str) -> IResult<&str, Almanac> {
-    move |input: &str| {
-        let (input, seeds) = terminated(parse_seeds(treat_as_range), tag("\n\n"))(input)?;
-        let (input, raw_maps) = separated_list0(tag("\n\n"), parse_map)(input)?;
-        let maps = raw_maps.try_into().expect("There must be exactly 7 maps");
+fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
+    let (input, seeds) = terminated(parse_seeds, tag("\n\n"))(input)?;
+    let (input, raw_maps) = separated_list0(tag("\n\n"), parse_map)(input)?;
+    let maps = raw_maps.try_into().expect("There must be exactly 7 maps");
 
-        Ok((input, Almanac::new(seeds, maps)))
-    }
+    Ok((input, Almanac::new(seeds, maps)))
 }
 
-pub fn parse_full_almanac(treat_as_range: bool) -> impl Fn(&str) -> Almanac {
-    move |input: &str| {
-        let (input, almanac) =
-            parse_almanac(treat_as_range)(input).expect("Failed to parse almanac");
-        assert_eq!(input, "");
-        almanac
-    }
+pub fn parse_full_almanac(input: &str) -> Almanac {
+    let (input, almanac) = parse_almanac(input).expect("Failed to parse almanac");
+    assert_eq!(input, "");
+    almanac
 }
 
 #[cfg(test)]
@@ -82,18 +61,7 @@ mod tests {
     fn test_parse_seeds() {
         let input = "seeds: 79 14 55 13";
 
-        assert_eq!(
-            parse_seeds(false)(input),
-            Ok((
-                "",
-                vec![
-                    ValueRange::single(79),
-                    ValueRange::single(14),
-                    ValueRange::single(55),
-                    ValueRange::single(13),
-                ]
-            ))
-        )
+        assert_eq!(parse_seeds(input), Ok(("", vec![79, 14, 55, 13])))
     }
 
     #[test]
@@ -165,16 +133,11 @@ humidity-to-location map:
 56 93 4";
 
         assert_eq!(
-            parse_almanac(false)(input),
+            parse_almanac(input),
             Ok((
                 "",
                 Almanac::new(
-                    vec![
-                        ValueRange::single(79),
-                        ValueRange::single(14),
-                        ValueRange::single(55),
-                        ValueRange::single(13),
-                    ],
+                    vec![79, 14, 55, 13],
                     [
                         // seed-to-soil
                         AlmanacMap::new(vec![
@@ -2,19 +2,36 @@ pub const MAP_COUNT: usize = 7;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Almanac {
-    pub seeds: Vec<ValueRange>,
+    pub seeds: Vec<u32>,
     maps: [AlmanacMap; MAP_COUNT],
 }
 
 impl Almanac {
-    pub fn new(seeds: Vec<ValueRange>, maps: [AlmanacMap; MAP_COUNT]) -> Self {
+    pub fn new(seeds: Vec<u32>, maps: [AlmanacMap; MAP_COUNT]) -> Self {
         Self { seeds, maps }
     }
 
     pub fn lowest_seed_location(&self) -> u32 {
+        let seeds = self.seeds.iter().copied().map(ValueRange::single).collect();
+
+        self.lowest_location(seeds)
+    }
+
+    /// The lowest location when the seeds are pairs of the start and the length of a range.
+    pub fn lowest_seed_range_location(&self) -> u32 {
+        let seed_ranges = self
+            .seeds
+            .chunks_exact(2)
+            .map(|pair| ValueRange::new(pair[0], pair[1]))
+            .collect();
+
+        self.lowest_location(seed_ranges)
+    }
+
+    fn lowest_location(&self, seeds: Vec<ValueRange>) -> u32 {
         self.maps
             .iter()
-            .fold(self.seeds.clone(), |acc, map| map.get_many(acc))
+            .fold(seeds, |acc, map| map.get_many(acc))
             .iter()
             .map(|source| {
                 debug_assert!(!source.is_empty());
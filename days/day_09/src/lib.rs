@@ -0,0 +1,75 @@
+use aoc_utils::AocDay;
+
+type Num = i32;
+
+pub struct Day09;
+
+impl AocDay<Num, Num> for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed<'a> = Vec<Vec<Num>>;
+
+    fn parse(input: &str) -> Vec<Vec<Num>> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_ascii_whitespace()
+                    .map(|num| num.parse::<Num>().expect("Invalid number"))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_1(input: &Vec<Vec<Num>>) -> Num {
+        input
+            .iter()
+            .map(|sequence| extrapolate_value_forwards(sequence))
+            .sum()
+    }
+
+    fn part_2(input: &Vec<Vec<Num>>) -> Num {
+        input
+            .iter()
+            .map(|sequence| extrapolate_value_backwards(sequence))
+            .sum()
+    }
+}
+
+fn extrapolate_value_forwards(sequence: &[Num]) -> Num {
+    if sequence.iter().all(|num| *num == 0) {
+        0
+    } else {
+        let next_sequence: Vec<_> = sequence
+            .windows(2)
+            .map(|window| window[1] - window[0])
+            .collect();
+        let next_diff = extrapolate_value_forwards(&next_sequence);
+        sequence.last().unwrap() + next_diff
+    }
+}
+
+fn extrapolate_value_backwards(sequence: &[Num]) -> Num {
+    if sequence.iter().all(|num| *num == 0) {
+        0
+    } else {
+        let next_sequence: Vec<_> = sequence
+            .windows(2)
+            .map(|window| window[1] - window[0])
+            .collect();
+        let next_diff = extrapolate_value_backwards(&next_sequence);
+        sequence.first().unwrap() - next_diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    aoc_utils::examples!(Day09 {
+        example: INPUT => { part_1: 114, part_2: 2 },
+    });
+}
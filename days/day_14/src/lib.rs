@@ -0,0 +1,64 @@
+use aoc_utils::AocDay;
+use types::Platform;
+
+mod types;
+
+pub struct Day14<const SIZE: usize>;
+
+impl<const SIZE: usize> AocDay<usize, usize> for Day14<SIZE> {
+    const DAY: u8 = 14;
+
+    type Parsed<'a> = Platform<SIZE>;
+
+    fn parse(input: &str) -> Platform<SIZE> {
+        Platform::parse(input)
+    }
+
+    fn part_1(input: &Platform<SIZE>) -> usize {
+        input.clone().tilt_north().total_load()
+    }
+
+    fn part_2(input: &Platform<SIZE>) -> usize {
+        const ITERATIONS: usize = 1_000_000_000;
+
+        let mut platform = input.clone();
+        let mut cache = Vec::with_capacity(100);
+
+        for iter in 1..=ITERATIONS {
+            cache.push(platform.clone());
+            platform = platform.spin_cycle();
+
+            if let Some(cache_idx) = cache.iter().position(|a| a == &platform) {
+                let cycle_size = iter - cache_idx;
+                let remaining = ITERATIONS - iter;
+                let remaining_short = remaining % cycle_size;
+
+                for _ in 0..remaining_short {
+                    platform = platform.spin_cycle();
+                }
+
+                break;
+            }
+        }
+
+        platform.total_load()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_utils::examples!(Day14::<10> {
+        example: "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...." => { part_1: 136, part_2: 64 },
+    });
+}